@@ -0,0 +1,192 @@
+use std::{
+    fmt::Write,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use util::{parser::ParseError, Day, Stage};
+
+pub struct Sampler {
+    pub warm_up: Duration,
+    pub measurement: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Sampler {
+            warm_up: Duration::from_millis(300),
+            measurement: Duration::from_secs(2),
+            min_samples: 5,
+            max_samples: 1000,
+        }
+    }
+}
+
+impl Sampler {
+    pub fn sample(&self, f: &mut dyn FnMut()) -> Vec<Duration> {
+        let start = Instant::now();
+        let mut runs = 0;
+        while runs == 0 || start.elapsed() < self.warm_up {
+            f();
+            runs += 1;
+        }
+        let per_run = start.elapsed().as_nanos() / runs;
+        let samples = (self.measurement.as_nanos() / per_run.max(1)) as usize;
+        (0..samples.clamp(self.min_samples, self.max_samples))
+            .map(|_| {
+                let start = Instant::now();
+                f();
+                start.elapsed()
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub day: u32,
+    pub stage: String,
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Estimate {
+    pub fn new(day: u32, stage: Stage, samples: &[Duration]) -> Estimate {
+        let mut ns = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        ns.sort_by(f64::total_cmp);
+        let n = ns.len() as f64;
+        let mean = ns.iter().sum::<f64>() / n;
+        let variance = ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let median = if ns.len() % 2 == 0 {
+            (ns[ns.len() / 2 - 1] + ns[ns.len() / 2]) / 2.0
+        } else {
+            ns[ns.len() / 2]
+        };
+        Estimate {
+            day,
+            stage: stage.to_string(),
+            samples: ns.len(),
+            min_ns: ns[0] as u64,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+
+    fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+pub fn bench_day(day: &Day, input: &str, sampler: &Sampler) -> Result<Vec<Estimate>, ParseError> {
+    let mut estimates = vec![];
+    day.bench(input, &mut |stage, f| {
+        estimates.push(Estimate::new(day.day, stage, &sampler.sample(f)))
+    })?;
+    Ok(estimates)
+}
+
+pub fn load(path: &Path) -> Result<Vec<Estimate>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    serde_json::from_str(&contents).map_err(|e| format!("invalid baseline {}: {e}", path.display()))
+}
+
+pub fn save(path: &Path, estimates: &[Estimate]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(estimates).map_err(|e| e.to_string())?;
+    std::fs::write(path, json + "\n")
+        .map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+pub fn table(estimates: &[Estimate], baseline: &[Estimate]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<7} {:>10} {:>10} {:>10} {:>10} {:>8} {:>8}\n",
+        "day", "stage", "median", "mean", "min", "stddev", "samples", "change"
+    );
+    for estimate in estimates {
+        let change = baseline
+            .iter()
+            .find(|b| b.day == estimate.day && b.stage == estimate.stage)
+            .map(|b| {
+                let change = (estimate.median_ns as f64 / b.median_ns.max(1) as f64 - 1.0) * 100.0;
+                format!("{change:+.1}%")
+            })
+            .unwrap_or_default();
+        writeln!(
+            table,
+            "{:>3}  {:<7} {:>10} {:>10} {:>10} {:>10} {:>8} {:>8}",
+            estimate.day,
+            estimate.stage,
+            format!("{:.1?}", estimate.median()),
+            format!("{:.1?}", Duration::from_nanos(estimate.mean_ns)),
+            format!("{:.1?}", Duration::from_nanos(estimate.min_ns)),
+            format!("{:.1?}", Duration::from_nanos(estimate.stddev_ns)),
+            estimate.samples,
+            change
+        )
+        .unwrap();
+    }
+    let total = estimates.iter().map(Estimate::median).sum::<Duration>();
+    writeln!(table, "total median {total:.1?}").unwrap();
+    table
+}
+
+#[test]
+fn test_estimate() {
+    let samples = [5, 1, 3, 2, 4].map(Duration::from_nanos);
+    let estimate = Estimate::new(17, Stage::Parse, &samples);
+    assert_eq!(
+        estimate,
+        Estimate {
+            day: 17,
+            stage: "parse".to_string(),
+            samples: 5,
+            min_ns: 1,
+            median_ns: 3,
+            mean_ns: 3,
+            stddev_ns: 1,
+        }
+    );
+    let even = Estimate::new(17, Stage::Parse, &[1, 2, 3, 10].map(Duration::from_nanos));
+    assert_eq!(even.median_ns, 2);
+}
+
+#[test]
+fn test_bench_day() {
+    let sampler = Sampler {
+        warm_up: Duration::ZERO,
+        measurement: Duration::ZERO,
+        min_samples: 3,
+        max_samples: 3,
+    };
+    let estimates = bench_day(
+        &Day::of::<dec9::Day9>(),
+        "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45",
+        &sampler,
+    )
+    .unwrap();
+    assert_eq!(
+        estimates
+            .iter()
+            .map(|e| (e.day, e.stage.as_str(), e.samples))
+            .collect::<Vec<_>>(),
+        vec![(9, "parse", 3), (9, "part A", 3), (9, "part B", 3)]
+    );
+
+    let baseline = vec![Estimate {
+        median_ns: estimates[0].median_ns * 2,
+        ..estimates[0].clone()
+    }];
+    let table = table(&estimates, &baseline);
+    assert_eq!(table.lines().count(), 5);
+    assert!(table.lines().nth(1).unwrap().trim_end().ends_with('%'));
+}
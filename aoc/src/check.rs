@@ -0,0 +1,125 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path};
+
+use util::{Answer, Part};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    days: BTreeMap<u32, BTreeMap<Part, String>>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Answers, String> {
+        let table: toml::Table = toml::from_str(s).map_err(|e| e.to_string())?;
+        let mut days = BTreeMap::new();
+        for (day, parts) in table {
+            let day_no = day
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u32>().ok())
+                .ok_or(format!("invalid day '{day}', expected e.g. [day17]"))?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("expected a table of parts for [{day}]"))?;
+            let mut answers = BTreeMap::new();
+            for (part, answer) in parts {
+                let answer = match answer {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(n) => n.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "answer for {day}.{part} must be a string or integer"
+                        ))
+                    }
+                };
+                answers.insert(Part::try_from(part.as_str())?, answer);
+            }
+            days.insert(day_no, answers);
+        }
+        Ok(Answers { days })
+    }
+
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Answers::parse(&contents).map_err(|e| format!("invalid answers {}: {e}", path.display()))
+    }
+
+    pub fn expected(&self, day: u32, part: Part) -> Option<&str> {
+        self.days.get(&day)?.get(&part).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, answer: &Answer) -> Outcome {
+        match self.expected(day, answer.part) {
+            Some(expected) if expected == answer.answer => Outcome::Pass,
+            Some(expected) => Outcome::Fail {
+                expected: expected.to_string(),
+                actual: answer.answer.clone(),
+            },
+            None => Outcome::Missing {
+                actual: answer.answer.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {expected}, got {actual})")
+            }
+            Outcome::Missing { actual } => write!(f, "missing (got {actual})"),
+        }
+    }
+}
+
+#[test]
+fn test_answers() {
+    let answers = Answers::parse(
+        "[day1]
+a = 142
+b = \"281\"
+
+[day17]
+a = 102
+",
+    )
+    .unwrap();
+    assert_eq!(answers.expected(1, Part::A), Some("142"));
+    assert_eq!(answers.expected(1, Part::B), Some("281"));
+    assert_eq!(answers.expected(17, Part::B), None);
+    assert_eq!(answers.expected(2, Part::A), None);
+
+    let answer = |part, answer: &str| Answer {
+        part,
+        answer: answer.to_string(),
+        time: std::time::Duration::ZERO,
+    };
+    assert_eq!(answers.check(1, &answer(Part::A, "142")), Outcome::Pass);
+    assert_eq!(
+        answers.check(1, &answer(Part::B, "280")),
+        Outcome::Fail {
+            expected: "281".to_string(),
+            actual: "280".to_string()
+        }
+    );
+    assert_eq!(
+        answers.check(17, &answer(Part::B, "94")),
+        Outcome::Missing {
+            actual: "94".to_string()
+        }
+    );
+
+    assert!(Answers::parse("[seventeen]\na = 1").is_err());
+    assert!(Answers::parse("[day1]\nc = 1").is_err());
+}
@@ -0,0 +1,49 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+const DEFAULT_CONFIG: &str = "aoc.toml";
+
+fn default_year() -> u32 {
+    2023
+}
+
+fn default_base_url() -> String {
+    "https://adventofcode.com".to_string()
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    #[serde(default = "default_year")]
+    pub year: u32,
+    #[serde(default = "default_base_url")]
+    pub base_url: String,
+}
+
+impl Config {
+    pub fn path(path: Option<PathBuf>) -> PathBuf {
+        path.or_else(|| std::env::var_os(CONFIG_VAR).map(PathBuf::from))
+            .unwrap_or(DEFAULT_CONFIG.into())
+    }
+
+    pub fn load(path: &Path) -> Result<Config, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        toml::from_str(&contents).map_err(|e| format!("invalid config {}: {e}", path.display()))
+    }
+}
+
+#[test]
+fn test_config() {
+    let config: Config = toml::from_str("session = \"abc123\"").unwrap();
+    assert_eq!(
+        config,
+        Config {
+            session: Some("abc123".to_string()),
+            year: 2023,
+            base_url: "https://adventofcode.com".to_string()
+        }
+    );
+}
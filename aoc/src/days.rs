@@ -0,0 +1,25 @@
+use util::Day;
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<dec1::Day1>(),
+        Day::of::<dec2::Day2>(),
+        Day::of::<dec3::Day3>(),
+        Day::of::<dec4::Day4>(),
+        Day::of::<dec5::Day5>(),
+        Day::of::<dec6::Day6>(),
+        Day::of::<dec7::Day7>(),
+        Day::of::<dec8::Day8>(),
+        Day::of::<dec9::Day9>(),
+        Day::of::<dec10::Day10>(),
+        Day::of::<dec11::Day11>(),
+        Day::of::<dec12::Day12>(),
+        Day::of::<dec13::Day13>(),
+        Day::of::<dec14::Day14>(),
+        Day::of::<dec15::Day15>(),
+        Day::of::<dec16::Day16>(),
+        Day::of::<dec17::Day17>(),
+        Day::of::<dec18::Day18>(),
+        Day::of::<dec19::Day19>(),
+    ]
+}
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+use util::input::InputLoader;
+
+use crate::config::Config;
+
+const USER_AGENT: &str = "github.com/Papperslappen/aoc2023 input fetcher";
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub struct Fetcher {
+    base_url: String,
+    year: u32,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(config: &Config) -> Result<Fetcher, String> {
+        let session = config
+            .session
+            .clone()
+            .ok_or("no session token in config, add `session = \"...\"`")?;
+        Ok(Fetcher {
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            year: config.year,
+            session,
+        })
+    }
+
+    pub fn url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, self.year, day)
+    }
+
+    pub fn fetch(&self, day: u32, loader: &InputLoader) -> Result<Fetched, String> {
+        let path = loader.path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let input = ureq::get(&self.url(day))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| format!("could not download input for day {day}: {e}"))?
+            .into_string()
+            .map_err(|e| format!("could not read input for day {day}: {e}"))?;
+
+        std::fs::create_dir_all(loader.root())
+            .map_err(|e| format!("could not create {}: {e}", loader.root().display()))?;
+        let partial = path.with_extension("part");
+        std::fs::write(&partial, input)
+            .and_then(|_| std::fs::rename(&partial, &path))
+            .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+fn serve(responses: Vec<&'static str>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        responses
+            .into_iter()
+            .map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = BufReader::new(&stream)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                stream.write_all(response.as_bytes()).unwrap();
+                request
+            })
+            .collect()
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_fetch() {
+    let (base_url, server) = serve(vec![
+        "HTTP/1.1 200 OK\r\nContent-Length: 12\r\nConnection: close\r\n\r\n1abc2\npqr3s\n",
+    ]);
+    let root = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let loader = InputLoader::new(&root);
+    let fetcher = Fetcher::new(&Config {
+        session: Some("s3cr3t".to_string()),
+        year: 2023,
+        base_url,
+    })
+    .unwrap();
+
+    let path = root.join("day01.txt");
+    assert_eq!(
+        fetcher.fetch(1, &loader),
+        Ok(Fetched::Downloaded(path.clone()))
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1abc2\npqr3s\n");
+    assert_eq!(fetcher.fetch(1, &loader), Ok(Fetched::Cached(path)));
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1"));
+    assert!(
+        requests[0].contains("\ncookie: session=s3cr3t")
+            || requests[0].contains("\nCookie: session=s3cr3t")
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_fetch_error() {
+    let (base_url, server) = serve(vec![
+        "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    ]);
+    let root = std::env::temp_dir().join(format!("aoc-fetch-error-{}", std::process::id()));
+    let loader = InputLoader::new(&root);
+    let fetcher = Fetcher::new(&Config {
+        session: Some("expired".to_string()),
+        year: 2023,
+        base_url,
+    })
+    .unwrap();
+
+    assert!(fetcher.fetch(2, &loader).is_err());
+    assert!(!loader.path(2).exists());
+    server.join().unwrap();
+}
@@ -0,0 +1,446 @@
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use util::{
+    input::{InputLoader, Source},
+    Part,
+};
+
+use crate::{
+    bench::Sampler,
+    check::{Answers, Outcome},
+    config::Config,
+    fetch::{Fetched, Fetcher},
+    report::Format,
+};
+
+mod bench;
+mod check;
+mod config;
+mod days;
+mod fetch;
+mod report;
+mod scaffold;
+
+const USAGE: &str = "usage: aoc run [DAYS] [--part a|b] [--input-dir DIR] [--format text|json]
+       aoc check [DAYS] [--input-dir DIR] [--answers FILE]
+       aoc bench [DAYS] [--input-dir DIR] [--time SECONDS] [--json FILE] [--baseline FILE]
+       aoc fetch DAYS [--input-dir DIR] [--config FILE]
+       aoc new DAY
+
+-v, -vv or -vvv (or $AOC_VERBOSE=1..3) print diagnostics from the
+solutions to stderr.
+
+DAYS is a single day (17), a range (1-5) or 'all' (the default for run,
+check and bench).
+Input for day N is read from DIR/dayNN.txt, where DIR defaults to
+$AOC_INPUT_DIR or ./inputs, and from stdin if that file does not exist.
+--format json prints one JSON record per answer with the day, part,
+answer and the parse and part times in nanoseconds.
+check compares every answer against FILE, which defaults to
+DIR/answers.toml and holds one table per day, e.g. [day17] a = 102.
+bench measures parse, part A and part B of every day for SECONDS each,
+optionally writing the results to FILE as JSON and comparing them with
+a previously written baseline.
+fetch downloads missing inputs into DIR using the session token in
+FILE, which defaults to $AOC_CONFIG or ./aoc.toml.
+new creates the crate decDAY from a template and registers it with the
+workspace, the runner and .vscode/launch.json. Run it from the
+workspace root.";
+
+enum Command {
+    Run {
+        days: RangeInclusive<u32>,
+        parts: Vec<Part>,
+        input_dir: Option<PathBuf>,
+        format: Format,
+    },
+    Check {
+        days: RangeInclusive<u32>,
+        input_dir: Option<PathBuf>,
+        answers: Option<PathBuf>,
+    },
+    Bench {
+        days: RangeInclusive<u32>,
+        input_dir: Option<PathBuf>,
+        sampler: Sampler,
+        json: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+    },
+    Fetch {
+        days: RangeInclusive<u32>,
+        input_dir: Option<PathBuf>,
+        config: Option<PathBuf>,
+    },
+    New {
+        day: u32,
+    },
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u32>, String> {
+    let day = |s: &str| s.parse::<u32>().map_err(|_| format!("invalid day '{s}'"));
+    if s == "all" {
+        return Ok(1..=25);
+    }
+    match s.split_once('-') {
+        Some((first, last)) => Ok(day(first)?..=day(last)?),
+        None => day(s).map(|day| day..=day),
+    }
+}
+
+#[test]
+fn test_parse_days() {
+    assert_eq!(parse_days("17"), Ok(17..=17));
+    assert_eq!(parse_days("3-7"), Ok(3..=7));
+    assert_eq!(parse_days("all"), Ok(1..=25));
+    assert!(parse_days("x").is_err());
+    assert!(parse_days("1-").is_err());
+}
+
+fn take_verbosity(args: impl Iterator<Item = String>) -> (Option<u8>, Vec<String>) {
+    let mut verbosity = None;
+    let args = args
+        .filter(|arg| match arg.as_str() {
+            "--verbose" => {
+                verbosity = Some(verbosity.unwrap_or(0) + 1);
+                false
+            }
+            s if s.len() > 1 && s.starts_with('-') && s[1..].chars().all(|c| c == 'v') => {
+                verbosity = Some(verbosity.unwrap_or(0) + s.len() as u8 - 1);
+                false
+            }
+            _ => true,
+        })
+        .collect();
+    (verbosity, args)
+}
+
+#[test]
+fn test_take_verbosity() {
+    let args = |s: &'static str| s.split(' ').map(String::from);
+    assert_eq!(
+        take_verbosity(args("run 17 -vv")),
+        (Some(2), vec!["run".to_string(), "17".to_string()])
+    );
+    assert_eq!(
+        take_verbosity(args("-v run --verbose")),
+        (Some(2), vec!["run".to_string()])
+    );
+    assert_eq!(take_verbosity(args("run")), (None, vec!["run".to_string()]));
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => {
+            let mut days = 1..=25;
+            let mut parts = Part::all().to_vec();
+            let mut input_dir = None;
+            let mut format = Format::Text;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--part" | "-p" => {
+                        let part = args.next().ok_or("missing value for --part")?;
+                        parts = vec![Part::try_from(part.as_str())?];
+                    }
+                    "--input-dir" | "-i" => {
+                        input_dir =
+                            Some(args.next().ok_or("missing value for --input-dir")?.into());
+                    }
+                    "--format" | "-f" => {
+                        let value = args.next().ok_or("missing value for --format")?;
+                        format = Format::try_from(value.as_str())?;
+                    }
+                    s => days = parse_days(s)?,
+                }
+            }
+            Ok(Command::Run {
+                days,
+                parts,
+                input_dir,
+                format,
+            })
+        }
+        Some("check") => {
+            let mut days = 1..=25;
+            let mut input_dir = None;
+            let mut answers = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input-dir" | "-i" => {
+                        input_dir =
+                            Some(args.next().ok_or("missing value for --input-dir")?.into());
+                    }
+                    "--answers" | "-a" => {
+                        answers = Some(args.next().ok_or("missing value for --answers")?.into());
+                    }
+                    s => days = parse_days(s)?,
+                }
+            }
+            Ok(Command::Check {
+                days,
+                input_dir,
+                answers,
+            })
+        }
+        Some("bench") => {
+            let mut days = 1..=25;
+            let mut input_dir = None;
+            let mut sampler = Sampler::default();
+            let mut json = None;
+            let mut baseline = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input-dir" | "-i" => {
+                        input_dir =
+                            Some(args.next().ok_or("missing value for --input-dir")?.into());
+                    }
+                    "--time" | "-t" => {
+                        let time = args.next().ok_or("missing value for --time")?;
+                        sampler.measurement = time
+                            .parse::<f64>()
+                            .ok()
+                            .and_then(|t| Duration::try_from_secs_f64(t).ok())
+                            .ok_or(format!("invalid time '{time}'"))?;
+                    }
+                    "--json" => {
+                        json = Some(args.next().ok_or("missing value for --json")?.into());
+                    }
+                    "--baseline" => {
+                        baseline = Some(args.next().ok_or("missing value for --baseline")?.into());
+                    }
+                    s => days = parse_days(s)?,
+                }
+            }
+            Ok(Command::Bench {
+                days,
+                input_dir,
+                sampler,
+                json,
+                baseline,
+            })
+        }
+        Some("fetch") => {
+            let mut days = None;
+            let mut input_dir = None;
+            let mut config = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input-dir" | "-i" => {
+                        input_dir =
+                            Some(args.next().ok_or("missing value for --input-dir")?.into());
+                    }
+                    "--config" | "-c" => {
+                        config = Some(args.next().ok_or("missing value for --config")?.into());
+                    }
+                    s => days = Some(parse_days(s)?),
+                }
+            }
+            Ok(Command::Fetch {
+                days: days.ok_or("missing days to fetch")?,
+                input_dir,
+                config,
+            })
+        }
+        Some("new") => {
+            let day = args.next().ok_or("missing day")?;
+            let day = day
+                .parse::<u32>()
+                .map_err(|_| format!("invalid day '{day}'"))?;
+            match args.next() {
+                Some(arg) => Err(format!("unexpected argument '{arg}'")),
+                None => Ok(Command::New { day }),
+            }
+        }
+        Some(command) => Err(format!("unknown command '{command}'")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn run(
+    days: RangeInclusive<u32>,
+    parts: &[Part],
+    loader: &InputLoader,
+    format: Format,
+) -> Result<(), String> {
+    let selected = days::all()
+        .into_iter()
+        .filter(|day| days.contains(&day.day))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err(format!(
+            "no solutions registered for days {}-{}",
+            days.start(),
+            days.end()
+        ));
+    }
+    for day in selected {
+        let source = loader.source(day.day);
+        if source == Source::Stdin {
+            eprintln!(
+                "{} not found, reading day {} input from stdin",
+                loader.path(day.day).display(),
+                day.day
+            );
+        }
+        let input = source
+            .read()
+            .map_err(|e| format!("could not read input for day {}: {e}", day.day))?;
+        let output = day
+            .run(input.raw(), parts)
+            .map_err(|e| format!("could not parse input for day {}: {e}", day.day))?;
+        print!("{}", format.render(day.day, &output));
+    }
+    Ok(())
+}
+
+fn check(days: RangeInclusive<u32>, answers: &Answers, loader: &InputLoader) -> Result<(), String> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days::all()
+        .into_iter()
+        .filter(|day| days.contains(&day.day))
+    {
+        let Source::File(path) = loader.source(day.day) else {
+            println!(
+                "Day {:>2}: missing input {}",
+                day.day,
+                loader.path(day.day).display()
+            );
+            missing += Part::all().len();
+            continue;
+        };
+        let input = Source::File(path)
+            .read()
+            .map_err(|e| format!("could not read input for day {}: {e}", day.day))?;
+        let answers_for_day = match day.run(input.raw(), &Part::all()) {
+            Ok(output) => output.answers,
+            Err(e) => {
+                println!("Day {:>2}: FAIL (could not parse input: {e})", day.day);
+                failed += Part::all().len();
+                continue;
+            }
+        };
+        for answer in answers_for_day {
+            let outcome = answers.check(day.day, &answer);
+            match outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Fail { .. } => failed += 1,
+                Outcome::Missing { .. } => missing += 1,
+            }
+            println!("Day {:>2} part {}: {outcome}", day.day, answer.part);
+        }
+    }
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        return Err(format!("check failed for {failed} part(s)"));
+    }
+    Ok(())
+}
+
+fn bench(
+    days: RangeInclusive<u32>,
+    sampler: &Sampler,
+    loader: &InputLoader,
+    baseline: &[bench::Estimate],
+) -> Result<Vec<bench::Estimate>, String> {
+    let mut estimates = vec![];
+    for day in days::all()
+        .into_iter()
+        .filter(|day| days.contains(&day.day))
+    {
+        let Source::File(path) = loader.source(day.day) else {
+            eprintln!(
+                "Day {}: skipped, {} not found",
+                day.day,
+                loader.path(day.day).display()
+            );
+            continue;
+        };
+        eprintln!("Day {}: benchmarking", day.day);
+        let input = Source::File(path)
+            .read()
+            .map_err(|e| format!("could not read input for day {}: {e}", day.day))?;
+        estimates.extend(
+            bench::bench_day(&day, input.raw(), sampler)
+                .map_err(|e| format!("could not parse input for day {}: {e}", day.day))?,
+        );
+    }
+    print!("{}", bench::table(&estimates, baseline));
+    Ok(estimates)
+}
+
+fn fetch(days: RangeInclusive<u32>, fetcher: &Fetcher, loader: &InputLoader) -> Result<(), String> {
+    for day in days {
+        match fetcher.fetch(day, loader)? {
+            Fetched::Cached(path) => println!("Day {day}: {} already exists", path.display()),
+            Fetched::Downloaded(path) => println!("Day {day}: downloaded {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let (verbosity, args) = take_verbosity(std::env::args().skip(1));
+    if let Some(verbosity) = verbosity {
+        util::trace::set_verbosity(verbosity);
+    }
+    let command = parse_args(args.into_iter()).unwrap_or_else(|e| {
+        eprintln!("error: {e}\n\n{USAGE}");
+        std::process::exit(2);
+    });
+    let result = match command {
+        Command::Run {
+            days,
+            parts,
+            input_dir,
+            format,
+        } => {
+            let loader = input_dir.map_or_else(InputLoader::from_env, InputLoader::new);
+            run(days, &parts, &loader, format)
+        }
+        Command::Check {
+            days,
+            input_dir,
+            answers,
+        } => {
+            let loader = input_dir.map_or_else(InputLoader::from_env, InputLoader::new);
+            let answers = answers.unwrap_or_else(|| loader.root().join("answers.toml"));
+            Answers::load(&answers).and_then(|answers| check(days, &answers, &loader))
+        }
+        Command::Bench {
+            days,
+            input_dir,
+            sampler,
+            json,
+            baseline,
+        } => {
+            let loader = input_dir.map_or_else(InputLoader::from_env, InputLoader::new);
+            baseline
+                .map_or(Ok(vec![]), |path| bench::load(&path))
+                .and_then(|baseline| bench(days, &sampler, &loader, &baseline))
+                .and_then(|estimates| json.map_or(Ok(()), |path| bench::save(&path, &estimates)))
+        }
+        Command::Fetch {
+            days,
+            input_dir,
+            config,
+        } => {
+            let loader = input_dir.map_or_else(InputLoader::from_env, InputLoader::new);
+            Config::load(&Config::path(config))
+                .and_then(|config| Fetcher::new(&config))
+                .and_then(|fetcher| fetch(days, &fetcher, &loader))
+        }
+        Command::New { day } => scaffold::new_day(Path::new("."), day).map(|dir| {
+            println!(
+                "Created {}, registered in Cargo.toml and aoc",
+                dir.display()
+            );
+        }),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,92 @@
+use serde::Serialize;
+use util::Output;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            s => Err(format!("invalid format '{s}', expected text or json")),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Record {
+    pub day: u32,
+    pub part: String,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub part_ns: u64,
+}
+
+impl Format {
+    pub fn render(&self, day: u32, output: &Output) -> String {
+        match self {
+            Format::Text => {
+                let mut text = format!("Day {day} (parsed in {:.1?})\n", output.parse_time);
+                for answer in &output.answers {
+                    text += &format!(
+                        "Answer puzzle {}: {} ({:.1?})\n",
+                        answer.part, answer.answer, answer.time
+                    );
+                }
+                text
+            }
+            Format::Json => output
+                .answers
+                .iter()
+                .map(|answer| {
+                    let record = Record {
+                        day,
+                        part: answer.part.to_string(),
+                        answer: answer.answer.clone(),
+                        parse_ns: output.parse_time.as_nanos() as u64,
+                        part_ns: answer.time.as_nanos() as u64,
+                    };
+                    serde_json::to_string(&record).unwrap() + "\n"
+                })
+                .collect(),
+        }
+    }
+}
+
+#[test]
+fn test_render() {
+    use std::time::Duration;
+    use util::{Answer, Part};
+
+    let output = Output {
+        parse_time: Duration::from_micros(12),
+        answers: vec![
+            Answer {
+                part: Part::A,
+                answer: "114".to_string(),
+                time: Duration::from_nanos(1500),
+            },
+            Answer {
+                part: Part::B,
+                answer: "2".to_string(),
+                time: Duration::from_nanos(400),
+            },
+        ],
+    };
+    assert_eq!(
+        Format::Json.render(9, &output),
+        "{\"day\":9,\"part\":\"A\",\"answer\":\"114\",\"parse_ns\":12000,\"part_ns\":1500}\n\
+         {\"day\":9,\"part\":\"B\",\"answer\":\"2\",\"parse_ns\":12000,\"part_ns\":400}\n"
+    );
+    assert_eq!(
+        Format::Text.render(9, &output),
+        "Day 9 (parsed in 12.0µs)\nAnswer puzzle A: 114 (1.5µs)\nAnswer puzzle B: 2 (400.0ns)\n"
+    );
+    assert!(Format::try_from("yaml").is_err());
+}
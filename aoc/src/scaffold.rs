@@ -0,0 +1,159 @@
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.in");
+
+const LAUNCH_TEMPLATE: &str = r#"        {
+            "type": "lldb",
+            "request": "launch",
+            "name": "Debug unit tests in library 'dec{day}'",
+            "cargo": {
+                "args": [
+                    "test",
+                    "--no-run",
+                    "--lib",
+                    "--package=dec{day}"
+                ],
+                "filter": {
+                    "name": "dec{day}",
+                    "kind": "lib"
+                }
+            },
+            "args": [],
+            "cwd": "${workspaceFolder}"
+        }"#;
+
+fn render(template: &str, day: u32) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+fn insert_after_last(contents: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let last = lines
+        .iter()
+        .rposition(|l| l.trim_start().starts_with(prefix))
+        .ok_or(format!("could not find a line starting with '{prefix}'"))?;
+    lines.insert(last + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_member(manifest: &str, day: u32) -> Result<String, String> {
+    insert_after_last(manifest, "\"dec", &format!("    \"dec{day}\","))
+}
+
+fn add_dependency(manifest: &str, day: u32) -> Result<String, String> {
+    insert_after_last(
+        manifest,
+        "dec",
+        &format!("dec{day} = {{path = \"../dec{day}\"}}"),
+    )
+}
+
+fn add_day(days: &str, day: u32) -> Result<String, String> {
+    insert_after_last(
+        days,
+        "Day::of::<",
+        &format!("        Day::of::<dec{day}::Day{day}>(),"),
+    )
+}
+
+fn add_launch_config(launch: &str, day: u32) -> Result<String, String> {
+    let end = launch
+        .rfind("        }\n    ]")
+        .ok_or("could not find the end of the launch configurations")?
+        + "        }".len();
+    Ok(format!(
+        "{},\n{}{}",
+        &launch[..end],
+        render(LAUNCH_TEMPLATE, day),
+        &launch[end..]
+    ))
+}
+
+pub fn new_day(root: &Path, day: u32) -> Result<PathBuf, String> {
+    let dir = root.join(format!("dec{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc/Cargo.toml");
+    let days = root.join("aoc/src/days.rs");
+    let launch = root.join(".vscode/launch.json");
+
+    let updated_manifest = add_member(&read(&manifest)?, day)?;
+    let updated_runner_manifest = add_dependency(&read(&runner_manifest)?, day)?;
+    let updated_days = add_day(&read(&days)?, day)?;
+    let updated_launch = if launch.exists() {
+        Some(add_launch_config(&read(&launch)?, day)?)
+    } else {
+        None
+    };
+
+    std::fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+    write(&dir.join("Cargo.toml"), &render(CARGO_TEMPLATE, day))?;
+    write(&dir.join("src/lib.rs"), &render(LIB_TEMPLATE, day))?;
+    write(&manifest, &updated_manifest)?;
+    write(&runner_manifest, &updated_runner_manifest)?;
+    write(&days, &updated_days)?;
+    if let Some(updated_launch) = updated_launch {
+        write(&launch, &updated_launch)?;
+    }
+    Ok(dir)
+}
+
+#[test]
+fn test_new_day() {
+    let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+    std::fs::create_dir_all(root.join(".vscode")).unwrap();
+    std::fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"util\",\n    \"dec1\",\n]\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("aoc/Cargo.toml"),
+        "[dependencies]\nutil = {path = \"../util\"}\ndec1 = {path = \"../dec1\"}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("aoc/src/days.rs"),
+        "pub fn all() -> Vec<Day> {\n    vec![\n        Day::of::<dec1::Day1>(),\n    ]\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join(".vscode/launch.json"),
+        "{\n    \"configurations\": [\n        {\n            \"name\": \"dec1\"\n        }\n    ]\n}\n",
+    )
+    .unwrap();
+
+    assert_eq!(new_day(&root, 2), Ok(root.join("dec2")));
+
+    let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+    assert_eq!(
+        read("Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"util\",\n    \"dec1\",\n    \"dec2\",\n]\n"
+    );
+    assert!(read("aoc/Cargo.toml").ends_with("dec2 = {path = \"../dec2\"}\n"));
+    assert!(read("aoc/src/days.rs")
+        .contains("Day::of::<dec1::Day1>(),\n        Day::of::<dec2::Day2>(),\n    ]"));
+    assert!(read("dec2/Cargo.toml").contains("name = \"dec2\""));
+    assert!(read("dec2/src/lib.rs").contains("impl Solution for Day2 {\n    const DAY: u32 = 2;"));
+    let launch = read(".vscode/launch.json");
+    assert!(launch.contains("        },\n        {\n            \"type\": \"lldb\""));
+    assert!(launch.contains("\"--package=dec2\""));
+    assert!(launch.ends_with("        }\n    ]\n}\n"));
+
+    assert!(new_day(&root, 2).is_err());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,114 @@
+use pom::parser::*;
+use std::fmt::Display;
+use util::{
+    parser::{parse_lines, ParseError},
+    Solution,
+};
+
+pub struct Calibration {
+    digits: Vec<i32>,
+    numbers: Vec<i32>,
+}
+
+fn calibration_value(numbers: &[i32]) -> i32 {
+    numbers
+        .first()
+        .zip(numbers.last())
+        .map_or(0, |(first, last)| first * 10 + last)
+}
+
+fn solution_a(input: &[Calibration]) -> i32 {
+    input.iter().map(|c| calibration_value(&c.digits)).sum()
+}
+
+#[test]
+fn test_solution_a() {
+    let input = parse(
+        r"1abc2
+    pqr3stu8vwx
+    a1b2c3d4e5f
+    treb7uchet",
+    )
+    .unwrap();
+    assert_eq!(solution_a(&input), 142)
+}
+
+fn word_parser<'a>() -> Parser<'a, u8, i32> {
+    let zero = (-seq(b"zero")).map(|_| 0);
+    let one = (-seq(b"one")).map(|_| 1);
+    let two = (-seq(b"two")).map(|_| 2);
+    let three = (-seq(b"three")).map(|_| 3);
+    let four = (-seq(b"four")).map(|_| 4);
+    let five = (-seq(b"five")).map(|_| 5);
+    let six = (-seq(b"six")).map(|_| 6);
+    let seven = (-seq(b"seven")).map(|_| 7);
+    let eight = (-seq(b"eight")).map(|_| 8);
+    let nine = (-seq(b"nine")).map(|_| 9);
+    zero | one | two | three | four | five | six | seven | eight | nine
+}
+
+fn calibration_parser<'a>() -> Parser<'a, u8, Calibration> {
+    let digit = one_of(b"0123456789").map(|b| Some(((b - b'0') as i32, true)));
+    let word = (word_parser() - skip(1)).map(|n| Some((n, false)));
+    (digit | word | skip(1).map(|_| None))
+        .repeat(1..)
+        .convert(|numbers| {
+            let numbers = numbers.into_iter().flatten().collect::<Vec<_>>();
+            if numbers.is_empty() {
+                return Err("no digit in line");
+            }
+            let digits = numbers
+                .iter()
+                .filter(|(_, digit)| *digit)
+                .map(|(n, _)| *n)
+                .collect();
+            let numbers = numbers.into_iter().map(|(n, _)| n).collect();
+            Ok(Calibration { digits, numbers })
+        })
+}
+
+fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    parse_lines(&calibration_parser(), input)
+}
+
+fn solution_b(input: &[Calibration]) -> i32 {
+    input.iter().map(|c| calibration_value(&c.numbers)).sum()
+}
+
+#[test]
+fn test_solution_b() {
+    let input = parse(
+        r"two1nine
+    eightwothree
+    abcone2threexyz
+    xtwone3four
+    4nineeightseven2
+    zoneight234
+    7pqrstsixteen",
+    )
+    .unwrap();
+    assert_eq!(solution_b(&input), 281);
+
+    let error = parse("1abc2\nabc\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(solution_a(&parse("1two\none").unwrap()), 11);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
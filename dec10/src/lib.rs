@@ -0,0 +1,154 @@
+use std::{collections::HashMap, fmt::Display};
+use util::{geometry::Polygon, parser::ParseError, search::bfs, Map, Point, Solution};
+
+struct Loopfinder<'a> {
+    map: &'a Map<Pipe>,
+}
+
+fn neighbors(Pipe(pipe): Pipe, coordinate: Point) -> Vec<Point> {
+    match pipe {
+        'S' => vec![],
+        '.' => vec![],
+        '|' => vec![(0, -1), (0, 1)],
+        '-' => vec![(-1, 0), (1, 0)],
+        'L' => vec![(0, -1), (1, 0)],
+        'J' => vec![(0, -1), (-1, 0)],
+        '7' => vec![(0, 1), (-1, 0)],
+        'F' => vec![(0, 1), (1, 0)],
+        _ => panic!("invalid character"),
+    }
+    .into_iter()
+    .map(|(dx, dy)| coordinate + Point::new(dx, dy))
+    .collect()
+}
+
+impl<'a> Loopfinder<'a> {
+    fn new(map: &'a Map<Pipe>) -> Loopfinder<'a> {
+        Loopfinder { map }
+    }
+
+    fn start_neighbors(&self, start: Point) -> impl Iterator<Item = Point> + '_ {
+        start.neighbors4().filter(move |coordinate| {
+            self.map
+                .get_point(*coordinate)
+                .is_some_and(|c| neighbors(*c, *coordinate).contains(&start))
+        })
+    }
+
+    fn trace_loop(self, start: Point) -> Vec<Point> {
+        let mut path = vec![start];
+        let mut current = self.start_neighbors(start).next().unwrap();
+        while current != start {
+            let previous = *path.last().unwrap();
+            path.push(current);
+            current = neighbors(*self.map.get_point(current).unwrap(), current)
+                .into_iter()
+                .find(|c| *c != previous)
+                .unwrap();
+        }
+        path
+    }
+
+    fn find_loop(self, start: Point) -> HashMap<Point, u64> {
+        bfs([start], |coordinate| -> Vec<Point> {
+            if *coordinate == start {
+                self.start_neighbors(start).collect()
+            } else {
+                neighbors(*self.map.get_point(*coordinate).unwrap(), *coordinate)
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Pipe(char);
+
+impl TryFrom<char> for Pipe {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if "|-LJ7F.S".contains(value) {
+            Ok(Pipe(value))
+        } else {
+            Err(format!("invalid pipe '{value}'"))
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Map<Pipe>, ParseError> {
+    Map::try_from_str(input)
+}
+
+fn solution_a(map: &Map<Pipe>) -> u64 {
+    let start = Point::from(map.find(&Pipe('S')).unwrap());
+
+    let pipeloop = Loopfinder::new(map).find_loop(start);
+    *pipeloop.values().max().unwrap()
+}
+
+fn solution_b(map: &Map<Pipe>) -> i64 {
+    let start = Point::from(map.find(&Pipe('S')).unwrap());
+
+    Polygon::new(Loopfinder::new(map).trace_loop(start)).interior_points()
+}
+
+#[test]
+fn test_solutions() {
+    let input1 = parse(
+        ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+    )
+    .unwrap();
+    let input2 = parse(
+        "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ",
+    )
+    .unwrap();
+    assert_eq!(solution_a(&input1), 4);
+    assert_eq!(solution_a(&input2), 8);
+
+    let input3 = parse(
+        ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...",
+    )
+    .unwrap();
+
+    assert_eq!(solution_b(&input1), 1);
+    assert_eq!(solution_b(&input3), 8);
+
+    let error = parse(".S7\n.#J").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Map<Pipe>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
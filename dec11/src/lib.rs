@@ -1,7 +1,9 @@
 use itertools::Itertools;
+use std::fmt::Display;
+use util::{parser::ParseError, Map, Solution};
 
 #[derive(Default)]
-struct StarMap {
+pub struct StarMap {
     stars: Vec<(u64, u64)>,
     width: u64,
     height: u64,
@@ -32,8 +34,7 @@ impl StarMap {
                 .count() as u64
     }
 
-    fn total_pair_distance(&mut self, scale: u64) -> u64 {
-        self.empty_cols_rows();
+    fn total_pair_distance(&self, scale: u64) -> u64 {
         self.stars
             .iter()
             .combinations(2)
@@ -46,58 +47,52 @@ impl StarMap {
     }
 }
 
-fn solution_a(input: &[String]) -> u64 {
-    let height = input.len() as u64;
-    let width = input.first().unwrap().len() as u64;
-    let stars = input
-        .iter()
-        .enumerate()
-        .flat_map(|(row, s)| {
-            s.chars()
-                .enumerate()
-                .map(move |(col, c)| ((col as u64, row as u64), c))
-                .filter(|(_, c)| *c == '#')
-                .map(|(coordinate, _)| coordinate)
-        })
+#[derive(Clone, PartialEq)]
+enum Space {
+    Galaxy,
+    Empty,
+}
+
+impl TryFrom<char> for Space {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Space::Galaxy),
+            '.' => Ok(Space::Empty),
+            c => Err(format!("invalid space '{c}'")),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<StarMap, ParseError> {
+    let map = Map::<Space>::try_from_str(input)?;
+    let stars = (0..map.height)
+        .cartesian_product(0..map.width)
+        .filter(|(row, col)| map.at((*col, *row)) == Some(Space::Galaxy))
+        .map(|(row, col)| (col as u64, row as u64))
         .collect::<Vec<_>>();
     let mut starmap = StarMap {
         stars,
-        height,
-        width,
+        height: map.height as u64,
+        width: map.width as u64,
         ..Default::default()
     };
+    starmap.empty_cols_rows();
+    Ok(starmap)
+}
 
+fn solution_a(starmap: &StarMap) -> u64 {
     starmap.total_pair_distance(2)
 }
 
-fn solution_b(input: &[String]) -> u64 {
-    let height = input.len() as u64;
-    let width = input.first().unwrap().len() as u64;
-    let stars = input
-        .iter()
-        .enumerate()
-        .flat_map(|(row, s)| {
-            s.chars()
-                .enumerate()
-                .map(move |(col, c)| ((col as u64, row as u64), c))
-                .filter(|(_, c)| *c == '#')
-                .map(|(coordinate, _)| coordinate)
-        })
-        .collect::<Vec<_>>();
-
-    let mut starmap = StarMap {
-        stars,
-        height,
-        width,
-        ..Default::default()
-    };
+fn solution_b(starmap: &StarMap) -> u64 {
     starmap.total_pair_distance(1000000)
 }
 
 #[test]
 fn test_solutions() {
-    use util::raw_to_strings;
-    let input = raw_to_strings(
+    let input = parse(
         "...#......
 .......#..
 #.........
@@ -108,14 +103,31 @@ fn test_solutions() {
 ..........
 .......#..
 #...#.....",
-    );
+    )
+    .unwrap();
     assert_eq!(solution_a(&input), 374);
     //assert_eq!(solution_b(&input), 0);
+
+    let error = parse("#.\n.o").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert!(parse("").is_err());
 }
 
-fn main() {
-    println!("input:");
-    let input = util::get_input_rows();
-    println!("Answer puzzle A: {}", solution_a(&input));
-    println!("Answer puzzle B: {}", solution_b(&input));
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = StarMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
 }
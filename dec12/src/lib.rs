@@ -0,0 +1,184 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use pom::utf8::*;
+use util::{
+    memo::Memo,
+    parser::{parse_lines, space, ParseError},
+    Solution,
+};
+
+#[derive(PartialEq, Eq, Clone)]
+enum Spring {
+    Working,
+    Damaged,
+    Unknown,
+}
+
+impl From<char> for Spring {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Working,
+            '#' => Self::Damaged,
+            '?' => Self::Unknown,
+            c => panic!("invalid character '{c}'"),
+        }
+    }
+}
+
+impl Display for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Spring::Working => '.',
+                Spring::Damaged => '#',
+                Spring::Unknown => '?',
+            }
+        )
+    }
+}
+
+struct SpringPuzzle<'a> {
+    springs: &'a [Spring],
+    groups: &'a [u64],
+}
+
+pub struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<u64>,
+}
+
+impl Record {
+    fn unfold(&self) -> Record {
+        Record {
+            springs: vec![self.springs.clone(); 5].join(&Spring::Unknown),
+            groups: self.groups.repeat(5),
+        }
+    }
+}
+
+fn record<'a>() -> pom::parser::Parser<'a, u8, Record> {
+    let springs_parser = (sym('.').repeat(1..).map(|_| Spring::Working)
+        | one_of("#?").map(Spring::from))
+    .repeat(1..);
+    let groups_parser = list(util::parser::utf8::posint(), sym(','));
+    space()
+        * (springs_parser + space() * groups_parser)
+            .map(|(springs, groups)| Record { springs, groups })
+}
+
+impl<'a> From<&'a Record> for SpringPuzzle<'a> {
+    fn from(record: &'a Record) -> Self {
+        SpringPuzzle {
+            springs: &record.springs,
+            groups: &record.groups,
+        }
+    }
+}
+
+impl Display for SpringPuzzle<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for s in self.springs.iter() {
+            write!(f, "{}", s)?;
+        }
+        write!(f, " ")?;
+        write!(f, "{}", self.groups.iter().map(|g| g.to_string()).join(","))
+    }
+}
+
+impl SpringPuzzle<'_> {
+    fn solve(&self) -> u64 {
+        let mut memo = Memo::new();
+        let arrangements = self.count(&mut memo, 0, 0);
+        util::trace!(
+            "{self}: {arrangements} ({} hits, {} misses)",
+            memo.hits(),
+            memo.misses()
+        );
+        arrangements
+    }
+
+    fn count(&self, memo: &mut Memo<(usize, usize), u64>, spring: usize, group: usize) -> u64 {
+        memo.get_or_insert_with((spring, group), |memo| match self.springs.get(spring) {
+            None => (group == self.groups.len()) as u64,
+            Some(Spring::Working) => self.count(memo, spring + 1, group),
+            Some(Spring::Damaged) => self.place(memo, spring, group),
+            Some(Spring::Unknown) => {
+                self.count(memo, spring + 1, group) + self.place(memo, spring, group)
+            }
+        })
+    }
+
+    fn place(&self, memo: &mut Memo<(usize, usize), u64>, spring: usize, group: usize) -> u64 {
+        let Some(&length) = self.groups.get(group) else {
+            return 0;
+        };
+        let end = spring + length as usize;
+        if end > self.springs.len()
+            || self.springs[spring..end].contains(&Spring::Working)
+            || self.springs.get(end) == Some(&Spring::Damaged)
+        {
+            0
+        } else {
+            self.count(memo, (end + 1).min(self.springs.len()), group + 1)
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    parse_lines(&record(), input)
+}
+
+fn solution_a(records: &[Record]) -> u64 {
+    records
+        .iter()
+        .map(|record| SpringPuzzle::from(record).solve())
+        .sum()
+}
+
+fn solution_b(records: &[Record]) -> u64 {
+    records
+        .iter()
+        .map(|record| SpringPuzzle::from(&record.unfold()).solve())
+        .sum()
+}
+
+#[test]
+fn test_solutions() {
+    let input = parse("?###???????? 3,2,1").unwrap();
+    assert_eq!(solution_a(&input), 10);
+    assert_eq!(solution_b(&input), 506250);
+
+    let input = parse(
+        "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+    )
+    .unwrap();
+    assert_eq!(solution_a(&input), 21);
+    assert_eq!(solution_b(&input), 525152);
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::Display;
+use util::{parser::ParseError, Map, Solution};
+
+#[derive(Clone)]
+enum Place {
+    Ash,
+    Rock,
+}
+
+impl TryFrom<char> for Place {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Place::Ash),
+            '#' => Ok(Place::Rock),
+            c => Err(format!("invalid place '{c}'")),
+        }
+    }
+}
+
+pub struct Square {
+    places: Map<Place>,
+}
+
+fn row_values(places: &Map<Place>) -> Vec<u64> {
+    places
+        .rows()
+        .map(|row| {
+            row.fold(0_u64, |acc, place| {
+                (acc << 1)
+                    + match place {
+                        Place::Ash => 0,
+                        Place::Rock => 1,
+                    }
+            })
+        })
+        .collect()
+}
+
+impl Square {
+    fn get_value_smudge_removed(&self) -> u64 {
+        (find_symmetry_with_single_bit_swap(&row_values(&self.places.transpose())).unwrap_or(0)
+            + 100 * find_symmetry_with_single_bit_swap(&row_values(&self.places)).unwrap_or(0))
+            as u64
+    }
+
+    fn get_value(&self) -> u64 {
+        (find_symmetry(&row_values(&self.places.transpose())).unwrap_or(0)
+            + 100 * find_symmetry(&row_values(&self.places)).unwrap_or(0)) as u64
+    }
+}
+
+fn find_symmetry(values: &[u64]) -> Option<usize> {
+    (1..values.len()).find(|mid| {
+        (1..=(values.len().abs_diff(*mid).min(*mid)))
+            .all(|i| values[mid - i] == values[mid + i - 1])
+    })
+}
+
+fn find_symmetry_with_single_bit_swap(values: &[u64]) -> Option<usize> {
+    (1..values.len())
+        .filter(|mid| {
+            (1..=(values.len().abs_diff(*mid).min(*mid)))
+                .map(|i| (values[mid - i], values[mid + i - 1]))
+                .all(|(a, b)| a == b || find_set_bit(a ^ b).is_some())
+        })
+        .find(|mid| {
+            (1..=(values.len().abs_diff(*mid).min(*mid)))
+                .map(|i| (values[mid - i], values[mid + i - 1]))
+                .filter(|(a, b)| find_set_bit(a ^ b).is_some())
+                .count()
+                == 1
+        })
+}
+
+fn find_set_bit(n: u64) -> Option<usize> {
+    if n.count_ones() == 1 {
+        Some(n.ilog2() as usize)
+    } else {
+        None
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Square>, ParseError> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let mut squares = vec![];
+    let mut line = 0;
+    for block in lines.split(|l| l.trim().is_empty()) {
+        if !block.is_empty() {
+            let places = Map::try_from_str(&block.join("\n")).map_err(|e| e.offset_lines(line))?;
+            squares.push(Square { places });
+        }
+        line += block.len() + 1;
+    }
+    Ok(squares)
+}
+
+fn solution_a(squares: &[Square]) -> u64 {
+    squares.iter().map(|square| square.get_value()).sum()
+}
+
+fn solution_b(squares: &[Square]) -> u64 {
+    squares
+        .iter()
+        .map(|square| square.get_value_smudge_removed())
+        .sum()
+}
+
+#[test]
+fn test_solutions() {
+    let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    let squares = parse(input).unwrap();
+    assert_eq!(solution_a(&squares), 405);
+    assert_eq!(solution_b(&squares), 400);
+
+    let crlf = format!("\r\n{}\r\n\r\n", input.replace('\n', "\r\n"));
+    assert_eq!(solution_a(&parse(&crlf).unwrap()), 405);
+
+    let error = parse("#.\n..\n\n##\n#x").err().unwrap();
+    assert_eq!((error.line, error.column), (5, 2));
+    let error = parse("#.\n..\n\n\n##\n#").err().unwrap();
+    assert_eq!((error.line, error.column), (6, 2));
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<Square>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
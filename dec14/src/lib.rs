@@ -0,0 +1,169 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
+
+use util::{cycle, parser::ParseError, Map, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Space {
+    Boulder,
+    Block,
+    Empty,
+}
+
+impl TryFrom<char> for Space {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Space::Boulder),
+            '#' => Ok(Space::Block),
+            '.' => Ok(Space::Empty),
+            c => Err(format!("invalid space '{c}'")),
+        }
+    }
+}
+
+impl From<Space> for char {
+    fn from(value: Space) -> Self {
+        match value {
+            Space::Boulder => 'O',
+            Space::Block => '#',
+            Space::Empty => '.',
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Hash, Eq)]
+pub struct Platform {
+    map: Map<Space>,
+}
+
+fn parse(input: &str) -> Result<Platform, ParseError> {
+    Ok(Platform {
+        map: Map::try_from_str(input)?,
+    })
+}
+
+impl Debug for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\n{}\n", self)
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.map)
+    }
+}
+
+impl Platform {
+    fn tilt_north(&mut self) -> &mut Self {
+        for col in 0..self.map.width {
+            let mut free = 0;
+            for row in 0..self.map.height {
+                match self.map[(col, row)] {
+                    Space::Block => free = row + 1,
+                    Space::Boulder => {
+                        self.map[(col, row)] = Space::Empty;
+                        self.map[(col, free)] = Space::Boulder;
+                        free += 1;
+                    }
+                    Space::Empty => (),
+                }
+            }
+        }
+        self
+    }
+
+    fn spin_cycle(&mut self) -> &mut Self {
+        for _ in 0..4 {
+            self.tilt_north();
+            self.map = self.map.rotate_cw();
+        }
+        self
+    }
+
+    fn load(&self) -> u64 {
+        self.map
+            .enumerate()
+            .filter(|(_, space)| **space == Space::Boulder)
+            .map(|((_, row), _)| (self.map.height - row) as u64)
+            .sum()
+    }
+}
+
+fn solution_a(platform: &Platform) -> u64 {
+    let mut platform = platform.clone();
+    platform.tilt_north();
+    platform.load()
+}
+
+fn spun(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+    platform.spin_cycle();
+    platform
+}
+
+fn solution_b(platform: &Platform, steps: usize) -> u64 {
+    cycle::state_after(platform.clone(), spun, steps).load()
+}
+
+#[test]
+fn test_solutions() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let platform = parse(input).unwrap();
+    assert_eq!(solution_a(&platform), 136);
+    let error = parse("O.#\n.x.").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    let n = 99;
+    let mut platform_s = platform.clone();
+    for _ in 0..n {
+        platform_s.spin_cycle();
+    }
+    assert_eq!(solution_b(&platform, n), platform_s.load());
+    assert_eq!(solution_b(&platform, 1000000000), 64);
+
+    let expected = cycle::Cycle {
+        prefix: 3,
+        period: 7,
+    };
+    assert_eq!(cycle::find(platform.clone(), spun), expected);
+    assert_eq!(cycle::brent(platform.clone(), spun), expected);
+    assert_eq!(cycle::floyd(platform.clone(), spun), expected);
+    assert_eq!(
+        expected
+            .state_after(platform.clone(), spun, 1000000000)
+            .load(),
+        64
+    );
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Platform;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input, 1000000000)
+    }
+}
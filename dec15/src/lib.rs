@@ -0,0 +1,121 @@
+use pom::{char_class::alpha, parser::*};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+};
+use util::{
+    parser::{parse_str, posint, space, ParseError},
+    Solution,
+};
+
+fn silly_hash(s: &String) -> u32 {
+    s.as_bytes().iter().fold(0_u32, |current_value, c| {
+        ((current_value + *c as u32) * 17) % 256
+    })
+}
+
+fn solution_a(ops: &[Op]) -> u32 {
+    ops.iter().map(|op| silly_hash(&op.to_string())).sum()
+}
+
+pub enum Op {
+    Eq(String, u32),
+    Minus(String),
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Eq(label, number) => write!(f, "{label}={number}"),
+            Op::Minus(label) => write!(f, "{label}-"),
+        }
+    }
+}
+
+fn label<'a>() -> Parser<'a, u8, String> {
+    is_a(alpha)
+        .repeat(1..)
+        .collect()
+        .convert(|u| String::from_utf8(Vec::from(u)))
+}
+
+fn op<'a>() -> Parser<'a, u8, Op> {
+    (label() + sym(b'=') * posint()).map(|(l, n)| Op::Eq(l, n))
+        | (label() - sym(b'-')).map(Op::Minus)
+}
+
+fn parse(input: &str) -> Result<Vec<Op>, ParseError> {
+    parse_str(&(list(op(), sym(b',')) - space() - end()), input)
+}
+
+fn solution_b(ops: &[Op]) -> u32 {
+    let mut boxes = HashMap::<u32, Vec<(String, u32)>>::new();
+    ops.iter().for_each(|op| match op {
+        Op::Eq(label, number) => {
+            let (label, number) = (label.clone(), *number);
+            let box_no = silly_hash(&label);
+            match boxes.entry(box_no) {
+                std::collections::hash_map::Entry::Occupied(mut v) => {
+                    let contents = v.get_mut();
+                    if let Some(p) = contents.iter().position(|(l, _)| *l == label) {
+                        contents[p] = (label, number)
+                    } else {
+                        contents.push((label, number))
+                    }
+                }
+                std::collections::hash_map::Entry::Vacant(v) => {
+                    v.insert(vec![(label, number)]);
+                }
+            };
+        }
+        Op::Minus(label) => {
+            let box_no = silly_hash(label);
+            if let Entry::Occupied(mut v) = boxes.entry(box_no) {
+                let contents = v.get_mut();
+                if let Some(p) = contents.iter().position(|(l, _)| l == label) {
+                    contents.remove(p);
+                }
+            }
+        }
+    });
+
+    (0..256)
+        .filter_map(|box_no| {
+            Some(
+                boxes
+                    .get(&box_no)?
+                    .iter()
+                    .enumerate()
+                    .map(|(slot, (_, n))| *n * (box_no + 1) * (slot as u32 + 1))
+                    .sum::<u32>(),
+            )
+        })
+        .sum()
+}
+
+#[test]
+fn test_solutions() {
+    assert_eq!(silly_hash(&"HASH".to_string()), 52);
+    let input = parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7").unwrap();
+    assert_eq!(solution_a(&input), 1320);
+    assert_eq!(solution_b(&input), 145);
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Vec<Op>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
@@ -0,0 +1,169 @@
+use std::{collections::HashSet, fmt::Display};
+
+use util::{parser::ParseError, Direction, Map, Solution};
+
+#[derive(PartialEq)]
+enum Feature {
+    MirrorLeft,
+    MirrorRight,
+    VerticalSplit,
+    HorisontalSplit,
+    Empty,
+}
+
+impl TryFrom<char> for Feature {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '\\' => Ok(Self::MirrorLeft),
+            '/' => Ok(Self::MirrorRight),
+            '-' => Ok(Self::HorisontalSplit),
+            '|' => Ok(Self::VerticalSplit),
+            '.' => Ok(Self::Empty),
+            c => Err(format!("invalid feature '{c}'")),
+        }
+    }
+}
+
+pub struct Floor {
+    features: Map<Feature>,
+}
+
+fn parse(input: &str) -> Result<Floor, ParseError> {
+    Ok(Floor {
+        features: Map::try_from_str(input)?,
+    })
+}
+
+impl Floor {
+    fn firing_my_lazor(&self, laser: ((usize, usize), Direction)) -> u64 {
+        let mut lasers = vec![laser];
+        let mut laser_visits = HashSet::<((usize, usize), Direction)>::new();
+
+        while let Some((coordinate, direction)) = lasers.pop() {
+            if laser_visits.insert((coordinate, direction)) {
+                match self.features[coordinate] {
+                    Feature::MirrorLeft => {
+                        let new_direction = if direction.is_horizontal() {
+                            direction.turn_right()
+                        } else {
+                            direction.turn_left()
+                        };
+                        if let Some(l) = new_direction.move_in(coordinate, self.features.size()) {
+                            lasers.push((l, new_direction))
+                        }
+                    }
+                    Feature::MirrorRight => {
+                        let new_direction = if direction.is_horizontal() {
+                            direction.turn_left()
+                        } else {
+                            direction.turn_right()
+                        };
+                        if let Some(l) = new_direction.move_in(coordinate, self.features.size()) {
+                            lasers.push((l, new_direction))
+                        }
+                    }
+                    Feature::VerticalSplit => match direction {
+                        Direction::E | Direction::W => {
+                            lasers.push((coordinate, direction.turn_left()));
+                            lasers.push((coordinate, direction.turn_right()));
+                        }
+                        _ => {
+                            if let Some(laser) = direction
+                                .move_in(coordinate, self.features.size())
+                                .map(|c| (c, direction))
+                            {
+                                lasers.push(laser)
+                            }
+                        }
+                    },
+                    Feature::HorisontalSplit => match direction {
+                        Direction::N | Direction::S => {
+                            lasers.push((coordinate, direction.turn_left()));
+                            lasers.push((coordinate, direction.turn_right()));
+                        }
+                        _ => {
+                            if let Some(laser) = direction
+                                .move_in(coordinate, self.features.size())
+                                .map(|c| (c, direction))
+                            {
+                                lasers.push(laser)
+                            }
+                        }
+                    },
+                    Feature::Empty => {
+                        if let Some(laser) = direction
+                            .move_in(coordinate, self.features.size())
+                            .map(|c| (c, direction))
+                        {
+                            lasers.push(laser)
+                        }
+                    }
+                }
+            }
+        }
+
+        laser_visits
+            .iter()
+            .map(|(c, _)| c)
+            .collect::<HashSet<_>>()
+            .len() as u64
+    }
+}
+
+fn solution_a(floor: &Floor) -> u64 {
+    floor.firing_my_lazor(((0_usize, 0_usize), Direction::E))
+}
+
+fn solution_b(floor: &Floor) -> u64 {
+    let (width, height) = floor.features.size();
+    (1..width)
+        .map(|col| ((col, 0), Direction::S))
+        .chain((1..width).map(|col| ((col, height - 1), Direction::N)))
+        .chain((1..height).map(|row| ((0, row), Direction::E)))
+        .chain((1..height).map(|row| ((width - 1, row), Direction::W)))
+        .map(|laser| floor.firing_my_lazor(laser))
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn test_solutions() {
+    let input1 = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+    let floor = parse(input1).unwrap();
+    assert_eq!(solution_a(&floor), 46);
+    assert_eq!(solution_b(&floor), 51);
+
+    let error = parse(".|.\n.x.").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert!(parse("").is_err());
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = Floor;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
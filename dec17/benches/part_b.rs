@@ -0,0 +1,33 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use dec17::{CrucibleMap, MapCost};
+use util::{
+    search::{BucketQueue, HeapQueue},
+    Map,
+};
+
+fn input() -> Map<MapCost> {
+    let mut seed = 17_u64;
+    Map::from_fn(141, 141, |_| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        MapCost::try_from(char::from_digit((seed >> 33) as u32 % 9 + 1, 10).unwrap()).unwrap()
+    })
+}
+
+fn part_b(c: &mut Criterion) {
+    let map = input();
+    let crucible = CrucibleMap::new(&map, 4..=10);
+    let mut group = c.benchmark_group("dec17 part B");
+    group.sample_size(10);
+    group.bench_function("heap", |b| {
+        b.iter(|| crucible.best_path(HeapQueue::new()).cost)
+    });
+    group.bench_function("bucket", |b| {
+        b.iter(|| crucible.best_path(BucketQueue::new()).cost)
+    });
+    group.finish();
+}
+
+criterion_group!(benches, part_b);
+criterion_main!(benches);
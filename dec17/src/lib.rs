@@ -0,0 +1,194 @@
+use std::{
+    fmt::Display,
+    ops::{Add, RangeInclusive},
+};
+
+use util::{
+    parser::ParseError,
+    search::{BucketQueue, Queue},
+    Dijkstra, Direction, Map, Path, Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct MapCost(u64);
+
+impl Add for MapCost {
+    type Output = MapCost;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MapCost(self.0 + rhs.0)
+    }
+}
+
+impl TryFrom<char> for MapCost {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        value
+            .to_digit(10)
+            .map(|cost| MapCost(cost as u64))
+            .ok_or(format!("invalid cost '{value}'"))
+    }
+}
+
+pub struct CrucibleMap<'a> {
+    map: &'a Map<MapCost>,
+    steps: RangeInclusive<usize>,
+}
+
+pub type State = ((usize, usize), Option<Direction>);
+
+impl<'a> CrucibleMap<'a> {
+    pub fn new(map: &'a Map<MapCost>, steps: RangeInclusive<usize>) -> Self {
+        CrucibleMap { map, steps }
+    }
+
+    pub fn best_path(&self, queue: impl Queue<(u64, State)>) -> Path<State> {
+        self.solve_with(queue, ((0, 0), None), |(c, _)| {
+            *c == (self.map.width - 1, self.map.height - 1)
+        })
+        .unwrap()
+    }
+}
+
+impl Dijkstra for CrucibleMap<'_> {
+    type State = State;
+
+    fn neighbors(
+        &self,
+        (coordinate, direction): &Self::State,
+    ) -> std::vec::Vec<(Self::State, u64)> {
+        let coordinate = *coordinate;
+        let directions = if let Some(previous_direction) = direction {
+            vec![
+                previous_direction.turn_left(),
+                previous_direction.turn_right(),
+            ]
+        } else {
+            Direction::all().to_vec()
+        };
+
+        let neighbors = directions
+            .iter()
+            .flat_map(|direction| {
+                (1..)
+                    .scan(
+                        ((coordinate, direction), 0),
+                        |((coordinate, direction), cost), _step| {
+                            if let Some(new) = self.map.move_direction(*coordinate, **direction) {
+                                *coordinate = new.clone().0;
+                                *cost += new.1 .0;
+                                Some(((*coordinate, **direction), *cost))
+                            } else {
+                                None
+                            }
+                        },
+                    )
+                    .skip(self.steps.start() - 1)
+                    .take(self.steps.end() - self.steps.start() + 1)
+                    .map(|((c, direction), cost)| ((c, Some(direction)), cost))
+            })
+            .collect();
+        neighbors
+    }
+}
+
+fn route(map: &Map<MapCost>, path: &Path<State>) -> Map<char> {
+    let mut route = Map::from_fn(map.width, map.height, |c| {
+        char::from_digit(map[c].0 as u32, 10).unwrap()
+    });
+    for ((from, _), (to, direction)) in path.states.iter().zip(path.states.iter().skip(1)) {
+        let direction = direction.unwrap();
+        let mut coordinate = *from;
+        while coordinate != *to {
+            coordinate = direction.move_in(coordinate, map.size()).unwrap();
+            route[coordinate] = match direction {
+                Direction::N => '^',
+                Direction::E => '>',
+                Direction::S => 'v',
+                Direction::W => '<',
+            };
+        }
+    }
+    route
+}
+
+fn solution_a(map: &Map<MapCost>) -> u64 {
+    let path = CrucibleMap::new(map, 1..=3).best_path(BucketQueue::new());
+    util::debug!("path: {:?}", path.states);
+    util::trace!("route:\n{}", route(map, &path));
+    path.cost
+}
+
+fn solution_b(map: &Map<MapCost>) -> u64 {
+    CrucibleMap::new(map, 4..=10)
+        .best_path(BucketQueue::new())
+        .cost
+}
+
+#[test]
+fn test_solutions() {
+    use util::search::HeapQueue;
+    let input1 = r"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+    let map = Map::<MapCost>::try_from_str(input1).unwrap();
+    assert_eq!(solution_a(&map), 102);
+    let crucible = CrucibleMap {
+        map: &map,
+        steps: 1..=3,
+    };
+    let path = crucible
+        .solve(((0, 0), None), |(c, _)| *c == (12, 12))
+        .unwrap();
+    assert_eq!(path.states.first(), Some(&((0, 0), None)));
+    assert_eq!(path.edges.iter().sum::<u64>(), 102);
+    let route = route(&map, &path);
+    assert_eq!(
+        route
+            .enumerate()
+            .filter(|(_, c)| !c.is_ascii_digit())
+            .map(|(c, _)| map[c].0)
+            .sum::<u64>(),
+        102
+    );
+    assert_eq!(solution_b(&map), 94);
+    assert_eq!(
+        CrucibleMap::new(&map, 4..=10)
+            .best_path(HeapQueue::new())
+            .cost,
+        94
+    );
+
+    let error = Map::<MapCost>::try_from_str("241\n3x5").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = Map<MapCost>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::try_from_str(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
@@ -0,0 +1,136 @@
+use pom::utf8::*;
+use std::fmt::Display;
+use util::{
+    geometry::Polygon,
+    parser::{
+        parse_lines,
+        utf8::{posint, space},
+        ParseError,
+    },
+    Direction, Solution,
+};
+
+#[allow(unused)]
+struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[allow(unused)]
+struct ContourCommand {
+    direction: Direction,
+    length: i64,
+    color: Color,
+}
+
+fn byte<'a>() -> Parser<'a, u8> {
+    one_of("01234567889ABCDEFabcdef")
+        .repeat(2)
+        .collect()
+        .convert(|s| u8::from_str_radix(s, 16))
+}
+
+fn color<'a>() -> Parser<'a, Color> {
+    (sym('#') * byte() + byte() + byte()).map(|((r, g), b)| Color { r, g, b })
+}
+
+fn parser<'a>() -> Parser<'a, ContourCommand> {
+    (one_of("UDLR").convert(Direction::try_from) - space() + posint() - space() - sym('(')
+        + color()
+        - sym(')'))
+    .map(|((direction, length), color)| ContourCommand {
+        direction,
+        length: length as i64,
+        color,
+    })
+}
+
+fn parser_b<'a>() -> Parser<'a, ContourCommand> {
+    ((one_of("UDLR").convert(Direction::try_from) - space() + posint() - space() - sym('('))
+        * (sym('#')
+            + one_of("01234567889ABCDEFabcdef")
+                .repeat(5)
+                .collect()
+                .convert(|s| i64::from_str_radix(s, 16))
+            + one_of("0123").map(|c| match c {
+                '0' => Direction::E,
+                '1' => Direction::S,
+                '2' => Direction::W,
+                _ => Direction::N,
+            }))
+        - sym(')'))
+    .map(|((_, length), direction)| ContourCommand {
+        length,
+        direction,
+        color: Color { r: 0, g: 0, b: 0 },
+    })
+}
+
+pub struct DigPlan {
+    commands: Vec<ContourCommand>,
+    hex_commands: Vec<ContourCommand>,
+}
+
+fn parse(input: &str) -> Result<DigPlan, ParseError> {
+    Ok(DigPlan {
+        commands: parse_lines(&parser(), input)?,
+        hex_commands: parse_lines(&parser_b(), input)?,
+    })
+}
+
+fn lagoon(commands: &[ContourCommand]) -> i64 {
+    let polygon = Polygon::from_commands(commands.iter().map(|c| (c.direction, c.length)));
+    util::debug!("{} corners", polygon.vertices().len());
+    polygon.lattice_points()
+}
+
+fn solution_a(commands: &[ContourCommand]) -> i64 {
+    lagoon(commands)
+}
+
+fn solution_b(commands: &[ContourCommand]) -> i64 {
+    lagoon(commands)
+}
+
+#[test]
+fn test_solutions() {
+    let input1 = parse(
+        r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)",
+    )
+    .unwrap();
+    assert_eq!(solution_a(&input1.commands), 62);
+    assert_eq!(solution_b(&input1.hex_commands), 952408144115);
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(&input.commands)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(&input.hex_commands)
+    }
+}
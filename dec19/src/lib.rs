@@ -1,7 +1,14 @@
-use std::{collections::HashMap, iter::once};
+use std::{collections::HashMap, fmt::Display};
 
 use pom::utf8::*;
-use util::parser::utf8::{posint, space};
+use util::{
+    parser::{
+        parse_str,
+        utf8::{posint, space},
+        ParseError,
+    },
+    Interval, Solution,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 enum Property {
@@ -53,13 +60,24 @@ type Rules = Vec<Rule>;
 type Label = String;
 
 #[derive(Debug)]
-struct Part {
+pub struct Part {
     x: u64,
     m: u64,
     a: u64,
     s: u64,
 }
 
+impl Property {
+    fn index(&self) -> usize {
+        match self {
+            Property::X => 0,
+            Property::M => 1,
+            Property::A => 2,
+            Property::S => 3,
+        }
+    }
+}
+
 impl Part {
     fn get(&self, property: &Property) -> u64 {
         match property {
@@ -95,7 +113,7 @@ fn rule_parser<'a>() -> Parser<'a, Rule> {
 }
 
 fn rules_parser<'a>() -> Parser<'a, (Label, Rules)> {
-    label_parser() - sym('{') + list::<'a, char, _, _>(rule_parser(), sym(',')) - sym('}')
+    label_parser() - sym('{') + list(rule_parser(), sym(',')) - sym('}')
 }
 
 fn part_parser<'a>() -> Parser<'a, Part> {
@@ -116,83 +134,61 @@ fn follow_the_rules(rules: &HashMap<Label, Rules>, part: &Part) -> Label {
     current_label
 }
 
-fn rules_tree_size(
-    rules: &HashMap<Label, Rules>,
-    label: Label,
-    partition: Vec<RuleCriteria>,
-) -> u64 {
-    match label.as_str() {
+type Partition = [Interval<u64>; 4];
+
+fn rules_tree_size(rules: &HashMap<Label, Rules>, label: &str, partition: Partition) -> u64 {
+    match label {
         "R" => 0,
-        "A" => partition_size(partition),
-        _ => {
-            let rule = rules.get(&label).unwrap();
-            rule.iter()
-                .scan(partition, |p, r| match &r.criteria {
+        "A" => partition_size(&partition),
+        _ => rules[label]
+            .iter()
+            .scan(partition, |partition, rule| {
+                let mut branch = *partition;
+                match &rule.criteria {
                     RuleCriteria::Less(property, n) => {
-                        let branch = p
-                            .iter()
-                            .cloned()
-                            .chain(once(r.criteria.clone()))
-                            .collect::<Vec<_>>();
-                        p.push(RuleCriteria::More(property.clone(), n - 1));
-                        Some(rules_tree_size(rules, r.destination.clone(), branch))
+                        let i = property.index();
+                        (branch[i], partition[i]) = partition[i].split_at(*n);
                     }
                     RuleCriteria::More(property, n) => {
-                        let branch = p
-                            .iter()
-                            .cloned()
-                            .chain(once(r.criteria.clone()))
-                            .collect::<Vec<_>>();
-                        p.push(RuleCriteria::Less(property.clone(), n + 1));
-                        Some(rules_tree_size(rules, r.destination.clone(), branch))
-                    }
-                    RuleCriteria::Always => {
-                        Some(rules_tree_size(rules, r.destination.clone(), p.clone()))
+                        let i = property.index();
+                        (partition[i], branch[i]) = partition[i].split_at(n.saturating_add(1));
                     }
-                })
-                .sum()
-        }
+                    RuleCriteria::Always => (),
+                }
+                Some(rules_tree_size(rules, &rule.destination, branch))
+            })
+            .sum(),
     }
 }
 
-fn partition_size(partition: Vec<RuleCriteria>) -> u64 {
-    use Property::*;
-    [X, M, A, S]
-        .into_iter()
-        .map(|property| {
-            partition
-                .iter()
-                .filter(|criteria| match criteria {
-                    RuleCriteria::Always => panic!("invalid criteria"),
-                    RuleCriteria::Less(p, _) | RuleCriteria::More(p, _) => *p == property,
-                })
-                .fold((1, 4000), |(lower, upper), criteria| match criteria {
-                    RuleCriteria::Less(_, n) => (lower, upper.min(*n - 1)),
-                    RuleCriteria::More(_, n) => (lower.max(*n + 1), upper),
-                    RuleCriteria::Always => panic!("invalid criteria"),
-                })
-        })
-        .map(|(lower, upper)| (upper - lower + 1).max(0))
-        .product()
-}
-
-fn solution_a(input: &str) -> u64 {
-    let parser = list::<'_, char, _, _>(rules_parser(), space())
+fn partition_size(partition: &Partition) -> u64 {
+    partition.iter().map(Interval::len).product()
+}
+
+pub struct System {
+    rules: HashMap<Label, Rules>,
+    parts: Vec<Part>,
+}
+
+fn parse(input: &str) -> Result<System, ParseError> {
+    let parser = list(rules_parser(), space())
         .map(|rules| rules.into_iter().collect::<HashMap<_, _>>())
-        + space() * list::<'_, char, _, _>(part_parser(), space());
-    let (rules, parts) = parser.parse(input.as_bytes()).unwrap();
-    parts
+        + space() * list(part_parser(), space());
+    let (rules, parts) = parse_str(&parser, input)?;
+    Ok(System { rules, parts })
+}
+
+fn solution_a(system: &System) -> u64 {
+    system
+        .parts
         .iter()
-        .filter(|part| follow_the_rules(&rules, part) == "A")
+        .filter(|part| follow_the_rules(&system.rules, part) == "A")
         .map(|part| part.x + part.m + part.a + part.s)
         .sum()
 }
 
-fn solution_b(input: &str) -> u64 {
-    let parser = list::<'_, char, _, _>(rules_parser(), space())
-        .map(|rules| rules.into_iter().collect::<HashMap<_, _>>());
-    let rules = parser.parse(input.as_bytes()).unwrap();
-    rules_tree_size(&rules, "in".to_string(), vec![])
+fn solution_b(system: &System) -> u64 {
+    rules_tree_size(&system.rules, "in", [Interval::new(1, 4001); 4])
 }
 
 #[test]
@@ -215,13 +211,30 @@ hdj{m>838:A,pv}
 {x=2461,m=1339,a=466,s=291}
 {x=2127,m=1623,a=2188,s=1013}";
 
-    assert_eq!(solution_a(input1), 19114);
-    assert_eq!(solution_b(input1), 167409079868000);
+    let system = parse(input1).unwrap();
+    assert_eq!(solution_a(&system), 19114);
+    assert_eq!(solution_b(&system), 167409079868000);
+
+    let system = parse("in{x<0:R,m>4000:R,a<2001:A,R}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+    assert_eq!(solution_a(&system), 10);
+    assert_eq!(solution_b(&system), 4000 * 4000 * 2000 * 4000);
 }
 
-fn main() {
-    println!("input:");
-    let input = util::get_input_rows().join("\n");
-    println!("Answer puzzle A: {}", solution_a(&input));
-    println!("Answer puzzle B: {}", solution_b(&input));
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = System;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
 }
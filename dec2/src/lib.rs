@@ -1,5 +1,6 @@
 use pom::parser::*;
-use util::{self, parser::*};
+use std::fmt::Display;
+use util::{self, parser::*, Solution};
 
 #[derive(Debug)]
 enum Color {
@@ -32,7 +33,7 @@ impl Draw {
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: u32,
     draws: Vec<Draw>,
 }
@@ -76,11 +77,13 @@ fn game<'a>() -> Parser<'a, u8, Game> {
         .map(|(id, draws)| Game { id, draws })
 }
 
-fn solution_a(input: &[String]) -> u32 {
-    let parser = game();
-    input
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_lines(&game(), input)
+}
+
+fn solution_a(games: &[Game]) -> u32 {
+    games
         .iter()
-        .map(|input| parser.parse(input.as_bytes()).unwrap())
         .filter(|game| !game.has_invalid())
         .map(|game| game.id)
         .sum()
@@ -88,39 +91,49 @@ fn solution_a(input: &[String]) -> u32 {
 
 #[test]
 fn test_solution_a() {
-    let input = util::raw_to_strings(
+    let input = parse(
         r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
-    );
+    )
+    .unwrap();
     assert_eq!(solution_a(&input), 8)
 }
 
-fn solution_b(input: &[String]) -> u32 {
-    let parser = game();
-    input
-        .iter()
-        .map(|line| parser.parse(line.as_bytes()).unwrap().power())
-        .sum()
+fn solution_b(games: &[Game]) -> u32 {
+    games.iter().map(|game| game.power()).sum()
 }
 
 #[test]
 fn test_solution_b() {
-    let input = util::raw_to_strings(
+    let input = parse(
         r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
-    );
+    )
+    .unwrap();
     assert_eq!(solution_b(&input), 2286)
 }
 
-fn main() {
-    println!("input:");
-    let input = util::get_input_rows();
-    println!("Answer puzzle A: {}", solution_a(&input));
-    println!("Answer puzzle B: {}", solution_b(&input));
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
 }
@@ -1,9 +1,12 @@
-use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
+use std::{collections::HashSet, fmt::Display};
+use util::{
+    parser::{parse_grid, ParseError},
+    Point, Solution, SparseMap,
+};
 
 fn solution_a(input: &[String]) -> u32 {
-    let mut numbers: Vec<(i32, i32, u32)> = vec![];
-    let mut symbols: HashMap<(i32, i32), char> = HashMap::new();
+    let mut numbers: Vec<(Point, u32)> = vec![];
+    let mut symbols: SparseMap<char> = SparseMap::new();
     let mut numberbuffer: Vec<char> = vec![];
     for (row, line) in input.iter().enumerate() {
         for (col, c) in line.chars().enumerate() {
@@ -16,12 +19,12 @@ fn solution_a(input: &[String]) -> u32 {
                         .collect::<String>()
                         .parse::<u32>()
                         .unwrap();
-                    numbers.push((row as i32, col as i32, number));
+                    numbers.push((Point::from((col, row)), number));
                     numberbuffer.clear();
                 }
                 if c == '.' {
                 } else {
-                    symbols.insert((row as i32, col as i32), c);
+                    symbols.set(Point::from((col, row)), c);
                 }
             }
         }
@@ -31,21 +34,20 @@ fn solution_a(input: &[String]) -> u32 {
                 .collect::<String>()
                 .parse::<u32>()
                 .unwrap();
-            numbers.push((row as i32, line.len() as i32, number));
+            numbers.push((Point::from((line.len(), row)), number));
             numberbuffer.clear();
         }
     }
 
     numbers
         .iter()
-        .filter(|(row, col, number)| {
+        .filter(|(end, number)| {
             let length = number.checked_ilog10().unwrap_or(0) + 1;
-            let rows = row - 1..=row + 1;
-            let cols = (*col - length as i32 - 1)..=*col;
-            rows.cartesian_product(cols)
-                .any(|(row, col)| symbols.contains_key(&(row, col)))
+            (1..=length as i64)
+                .flat_map(|i| (*end - Point::new(i, 0)).neighbors8())
+                .any(|p| symbols.contains(p))
         })
-        .map(|(_, _, number)| number)
+        .map(|(_, number)| number)
         .sum()
 }
 
@@ -67,9 +69,9 @@ fn test_solution_a() {
 }
 
 fn solution_b(input: &[String]) -> u32 {
-    let mut stars = Vec::<(i32, i32)>::new();
+    let mut stars = Vec::<Point>::new();
     let mut serial_number = 0;
-    let mut numbers = HashMap::<(i32, i32), (u32, i32)>::new();
+    let mut numbers = SparseMap::<(u32, i32)>::new();
     let mut numberbuffer: Vec<char> = vec![];
     for (row, line) in input.iter().enumerate() {
         for (col, c) in line.chars().enumerate() {
@@ -83,13 +85,13 @@ fn solution_b(input: &[String]) -> u32 {
                         .parse::<u32>()
                         .unwrap();
                     (1..=numberbuffer.len()).for_each(|i| {
-                        numbers.insert((row as i32, (col - i) as i32), (number, serial_number));
+                        numbers.set(Point::from((col - i, row)), (number, serial_number));
                     });
                     serial_number += 1;
                     numberbuffer.clear();
                 }
                 if c == '*' {
-                    stars.push((row as i32, col as i32));
+                    stars.push(Point::from((col, row)));
                 }
             }
         }
@@ -100,10 +102,7 @@ fn solution_b(input: &[String]) -> u32 {
                 .parse::<u32>()
                 .unwrap();
             (1..=numberbuffer.len()).for_each(|i| {
-                numbers.insert(
-                    (row as i32, (line.len() - i) as i32),
-                    (number, serial_number),
-                );
+                numbers.set(Point::from((line.len() - i, row)), (number, serial_number));
             });
             serial_number += 1;
             numberbuffer.clear();
@@ -112,10 +111,10 @@ fn solution_b(input: &[String]) -> u32 {
 
     stars
         .iter()
-        .map(|(row, col)| {
-            let adjecent_numbers: HashSet<(u32, i32)> = ((row - 1)..=(row + 1))
-                .cartesian_product((col - 1)..=(col + 1))
-                .filter_map(|(row, col)| numbers.get(&(row, col)))
+        .map(|star| {
+            let adjecent_numbers: HashSet<(u32, i32)> = star
+                .neighbors8()
+                .filter_map(|p| numbers.get(p))
                 .cloned()
                 .collect();
             adjecent_numbers
@@ -145,9 +144,52 @@ fn test_solution_b() {
     assert_eq!(solution_b(&input), 467835)
 }
 
-fn main() {
-    println!("input:");
-    let input = util::get_input_rows();
-    println!("Answer puzzle A: {}", solution_a(&input));
-    println!("Answer puzzle B: {}", solution_b(&input));
+struct Cell(char);
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if value.is_ascii_digit() || value.is_ascii_punctuation() {
+            Ok(Cell(value))
+        } else {
+            Err(format!("invalid cell '{value}'"))
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let (cells, width) = parse_grid::<Cell>(input)?;
+    Ok(cells
+        .chunks(width)
+        .map(|row| row.iter().map(|Cell(c)| c).collect())
+        .collect())
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("4.*\r\n.1.\n").unwrap(), vec!["4.*", ".1."]);
+    let error = parse("4.*\n.a.").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    let error = parse("4.*\n.1").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
 }
@@ -1,27 +1,26 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt::Display};
 
-use pom::parser::{seq, sym};
-use util::parser::{posint, space};
+use pom::parser::{seq, sym, Parser};
+use util::{
+    parser::{parse_lines, posint, space, ParseError},
+    Solution,
+};
 
 #[derive(Debug)]
-struct Card {
+pub struct Card {
     ticket: Vec<u32>,
     winning: HashSet<u32>,
 }
 
-impl From<&str> for Card {
-    fn from(value: &str) -> Self {
-        let parser = (seq(b"Card") * space() * posint() - sym(b':') - space()
-            + (posint() - space()).repeat(1..)
-            - sym(b'|')
-            - space()
-            + (posint() - space()).repeat(1..))
-        .map(|((_n, v1), v2)| Card {
-            ticket: v2,
-            winning: HashSet::from_iter(v1),
-        });
-        parser.parse(value.as_bytes()).unwrap()
-    }
+fn card<'a>() -> Parser<'a, u8, Card> {
+    (seq(b"Card") * space() * posint() - sym(b':') - space() + (posint() - space()).repeat(1..)
+        - sym(b'|')
+        - space()
+        + (posint() - space()).repeat(1..))
+    .map(|((_n, v1), v2)| Card {
+        ticket: v2,
+        winning: HashSet::from_iter(v1),
+    })
 }
 
 impl Card {
@@ -40,40 +39,41 @@ impl Card {
     }
 }
 
-fn solution_a(input: &[String]) -> u32 {
-    input.iter().map(|s| Card::from(s.as_str()).score()).sum()
+fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(&card(), input)
+}
+
+fn solution_a(cards: &[Card]) -> u32 {
+    cards.iter().map(|card| card.score()).sum()
 }
 
 #[test]
 fn test_solution_a() {
-    let input = util::raw_to_strings(
+    let input = parse(
         r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
-    );
+    )
+    .unwrap();
     assert_eq!(solution_a(&input), 13)
 }
 
 #[allow(clippy::needless_range_loop)]
-fn solution_b(input: &[String]) -> u32 {
+fn solution_b(cards: &[Card]) -> u32 {
     let cards_numbers = std::iter::repeat(1_u32)
-        .take(input.len())
-        .collect::<Vec<_>>();
-    let matches = input
-        .iter()
-        .map(|s| Card::from(s.as_str()).matches())
+        .take(cards.len())
         .collect::<Vec<_>>();
+    let matches = cards.iter().map(|card| card.matches()).collect::<Vec<_>>();
     let cards =
         matches
             .iter()
             .enumerate()
             .fold(cards_numbers, |mut card_numbers, (index, number)| {
-                //println!("index: {}",index);
                 let ncards = card_numbers[index];
-                //println!("Card {}: {} copies with {} wins", index, ncards, number);
+                util::trace!("card {}: {} copies with {} wins", index, ncards, number);
                 for j in (index + 1)..=index + *number as usize {
                     card_numbers[j] += ncards;
                 }
@@ -85,20 +85,33 @@ fn solution_b(input: &[String]) -> u32 {
 
 #[test]
 fn test_solution_b() {
-    let input = util::raw_to_strings(
+    let input = parse(
         r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
-    );
+    )
+    .unwrap();
     assert_eq!(solution_b(&input), 30)
 }
 
-fn main() {
-    println!("input:");
-    let input = util::get_input_rows();
-    println!("Answer puzzle A: {}", solution_a(&input));
-    println!("Answer puzzle B: {}", solution_b(&input));
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
 }
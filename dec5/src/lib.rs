@@ -0,0 +1,270 @@
+use pom::{char_class::alpha, parser::*};
+use std::fmt::Display;
+use util::{
+    self,
+    parser::{parse_str, posint, space, ParseError},
+    Interval, RangeSet, Solution,
+};
+
+#[derive(Debug, Clone)]
+struct MapRange {
+    dest: u64,
+    source: Interval<u64>,
+}
+
+impl MapRange {
+    fn map(&self, input: u64) -> Option<u64> {
+        self.source
+            .contains(input)
+            .then(|| self.dest + (input - self.source.start))
+    }
+
+    fn map_interval(&self, interval: &Interval<u64>) -> Interval<u64> {
+        Interval::new(
+            self.dest + (interval.start - self.source.start),
+            self.dest + (interval.end - self.source.start),
+        )
+    }
+}
+
+#[test]
+fn test_map() {
+    let range = MapRange {
+        dest: 10000,
+        source: Interval::new(5, 10),
+    };
+    assert_eq!(range.map(4), None);
+    assert_eq!(range.map(5), Some(10000));
+    assert_eq!(range.map(9), Some(10004));
+    assert_eq!(range.map(10), None);
+    assert_eq!(
+        range.map_interval(&Interval::new(6, 8)),
+        Interval::new(10001, 10003)
+    );
+
+    let range = map_range().parse(b"0 4000000000 500000000\n").unwrap();
+    assert_eq!(range.source, Interval::new(4000000000, 4500000000));
+    assert_eq!(range.map(4499999999), Some(499999999));
+    assert_eq!(range.map(4500000000), None);
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    name: String,
+    ranges: Vec<MapRange>,
+}
+
+impl Map {
+    fn map(&self, input: u64) -> u64 {
+        let result = self
+            .ranges
+            .iter()
+            .filter_map(|range| range.map(input))
+            .next()
+            .unwrap_or(input);
+        result
+    }
+
+    fn map_ranges(&self, seed_ranges: &RangeSet<u64>) -> RangeSet<u64> {
+        let sources = self
+            .ranges
+            .iter()
+            .map(|range| range.source)
+            .collect::<RangeSet<_>>();
+        let mapped = self
+            .ranges
+            .iter()
+            .flat_map(|range| {
+                seed_ranges
+                    .intersection(&range.source.into())
+                    .iter()
+                    .map(|interval| range.map_interval(interval))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<RangeSet<_>>();
+        let ranges = mapped.union(&seed_ranges.difference(&sources));
+        util::trace!("{} maps {:?} onto {:?}", self.name, seed_ranges, ranges);
+        ranges
+    }
+}
+
+#[test]
+fn test_map_range() {
+    let map = Map {
+        name: "light-to-temperature".to_owned(),
+        ranges: vec![
+            MapRange {
+                dest: 45,
+                source: Interval::new(77, 100),
+            },
+            MapRange {
+                dest: 81,
+                source: Interval::new(45, 64),
+            },
+            MapRange {
+                dest: 68,
+                source: Interval::new(64, 77),
+            },
+        ],
+    };
+    let result = map.map_ranges(&Interval::new(74, 88).into());
+    assert_eq!(
+        result.iter().copied().collect::<Vec<_>>(),
+        vec![Interval::new(45, 56), Interval::new(78, 81)]
+    );
+    let result = map.map_ranges(&Interval::new(40, 46).into());
+    assert_eq!(
+        result.iter().copied().collect::<Vec<_>>(),
+        vec![Interval::new(40, 45), Interval::new(81, 82)]
+    );
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    fn seeds_to_soil(&self) -> Vec<u64> {
+        self.maps.iter().fold(self.seeds.clone(), |numbers, map| {
+            numbers.iter().map(|n| map.map(*n)).collect()
+        })
+    }
+}
+
+#[derive(Debug)]
+struct NewAlmanac {
+    seed_ranges: RangeSet<u64>,
+    maps: Vec<Map>,
+}
+
+impl From<&Almanac> for NewAlmanac {
+    fn from(val: &Almanac) -> Self {
+        let seed_ranges = val
+            .seeds
+            .chunks(2)
+            .map(|chunk| Interval::new(chunk[0], chunk[0] + chunk[1]))
+            .collect();
+        NewAlmanac {
+            seed_ranges,
+            maps: val.maps.clone(),
+        }
+    }
+}
+
+impl NewAlmanac {
+    fn seeds_to_soil(&self) -> RangeSet<u64> {
+        self.maps
+            .iter()
+            .fold(self.seed_ranges.clone(), |seed_ranges, map| {
+                util::debug!("seed ranges before {}: {:?}", map.name, &seed_ranges);
+                map.map_ranges(&seed_ranges)
+            })
+    }
+}
+
+fn map_range<'a>() -> Parser<'a, u8, MapRange> {
+    (posint() - space() + posint() - space() + posint() - space()).map(
+        |((first, second), third)| MapRange {
+            dest: first as u64,
+            source: Interval::new(second as u64, second as u64 + third as u64),
+        },
+    )
+}
+
+fn map<'a>() -> Parser<'a, u8, Map> {
+    ((is_a(alpha) | sym(b'-'))
+        .repeat(1..)
+        .convert(String::from_utf8)
+        - (space() + seq(b"map:") + space())
+        - space()
+        + map_range().repeat(1..)
+        - space())
+    .map(|(name, ranges)| Map { name, ranges })
+}
+
+fn almanac<'a>() -> Parser<'a, u8, Almanac> {
+    (seq(b"seeds:") * space() * (posint() - space()).map(|s| s as u64).repeat(1..)
+        + (map() - space()).repeat(1..)
+        - end())
+    .map(|(seeds, maps)| Almanac { seeds, maps })
+}
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    parse_str(&almanac(), input)
+}
+
+fn solution_a(almanac: &Almanac) -> u64 {
+    let soil = almanac.seeds_to_soil();
+    *soil.iter().min().unwrap()
+}
+
+fn solution_b(almanac: &Almanac) -> u64 {
+    let new_almanac: NewAlmanac = almanac.into();
+    let location = new_almanac.seeds_to_soil();
+    util::debug!("location ranges: {:?}", location);
+    location.min().unwrap()
+}
+
+#[test]
+fn test_solutions() {
+    let input = parse(
+        r"seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4
+        ",
+    )
+    .unwrap();
+    assert_eq!(solution_a(&input), 35);
+    assert_eq!(solution_b(&input), 46);
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
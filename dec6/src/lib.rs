@@ -0,0 +1,108 @@
+use pom::parser::*;
+use std::fmt::Display;
+use util::{
+    parser::{parse_str, posint, space, ParseError},
+    Solution,
+};
+
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn ways_to_win(&self) -> u64 {
+        let time = self.time as f64;
+        let distance = self.distance as f64;
+
+        let lower = time / 2.0 - ((time / 2.0) * (time / 2.0) - distance).sqrt();
+        let upper = time / 2.0 + ((time / 2.0) * (time / 2.0) - distance).sqrt();
+        (largest_int_less_than(upper) - smallest_int_bigger_than(lower)) as u64 + 1
+    }
+}
+
+fn largest_int_less_than(x: f64) -> i32 {
+    let y = x.floor() as i32;
+
+    if (y as f64 - x).abs() < f64::EPSILON {
+        y - 1
+    } else {
+        y
+    }
+}
+
+fn smallest_int_bigger_than(x: f64) -> i32 {
+    let y = x.ceil() as i32;
+    if (y as f64 - x).abs() < f64::EPSILON {
+        y + 1
+    } else {
+        y
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+    let time_parser = seq(b"Time:") * space() * list(posint(), space());
+    let distance_parser = seq(b"Distance:") * space() * list(posint(), space());
+    let parser = (time_parser - space() + distance_parser).map(|(time, distance)| {
+        time.into_iter()
+            .zip(distance)
+            .map(|(time, distance)| Race {
+                time: time as u64,
+                distance: distance as u64,
+            })
+            .collect()
+    });
+    parse_str(&parser, input)
+}
+
+fn solution_a(races: &[Race]) -> u64 {
+    races
+        .iter()
+        .map(|race| race.ways_to_win())
+        .reduce(|a, b| a * b)
+        .unwrap()
+}
+
+fn concatenate(numbers: impl Iterator<Item = u64>) -> u64 {
+    numbers.fold(0, |acc, n| {
+        acc * 10_u64.pow(n.checked_ilog10().unwrap_or(0) + 1) + n
+    })
+}
+
+fn solution_b(races: &[Race]) -> u64 {
+    let race = Race {
+        time: concatenate(races.iter().map(|race| race.time)),
+        distance: concatenate(races.iter().map(|race| race.distance)),
+    };
+    race.ways_to_win()
+}
+
+#[test]
+fn test_solutions() {
+    let input = parse(
+        "Time:      7  15   30
+Distance:  9  40  200",
+    )
+    .unwrap();
+    assert_eq!(solution_a(&input), 288);
+    assert_eq!(solution_b(&input), 71503);
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
@@ -1,5 +1,9 @@
 use pom::utf8::*;
-use util::parser::space;
+use std::fmt::Display;
+use util::{
+    parser::{parse_lines, space, ParseError},
+    Solution,
+};
 
 mod solution_a {
     use util::count_unique;
@@ -210,18 +214,31 @@ mod solution_b {
     }
 }
 
-fn solution_a(input: &[String]) -> u64 {
+pub struct Bid {
+    cards: Vec<char>,
+    bet: u64,
+}
+
+fn parse(input: &str) -> Result<Vec<Bid>, ParseError> {
     let digit = one_of("0123456789");
     let integer = digit.discard().repeat(1..);
-    let parser = space()
-        * one_of("23456789TJQKA")
-            .convert(solution_a::Card::try_from)
-            .repeat(5)
-        + space() * integer.collect().convert(|x| x.parse::<u64>());
-    let mut hands = input
+    let parser = (space() * one_of("23456789TJQKA").repeat(5)
+        + space() * integer.collect().convert(|x| x.parse::<u64>()))
+    .map(|(cards, bet)| Bid { cards, bet });
+    parse_lines(&parser, input)
+}
+
+fn solution_a(bids: &[Bid]) -> u64 {
+    let mut hands = bids
         .iter()
-        .map(|s| parser.parse(s.as_bytes()).unwrap())
-        .map(|(cards, bet)| (solution_a::Hand::from_cards(cards), bet))
+        .map(|bid| {
+            let cards = bid
+                .cards
+                .iter()
+                .map(|c| solution_a::Card::try_from(*c).unwrap())
+                .collect();
+            (solution_a::Hand::from_cards(cards), bid.bet)
+        })
         .collect::<Vec<_>>();
     hands.sort_by(|(hand1, _), (hand2, _)| hand1.cmp(hand2));
     hands
@@ -231,18 +248,17 @@ fn solution_a(input: &[String]) -> u64 {
         .sum()
 }
 
-fn solution_b(input: &[String]) -> u64 {
-    let digit = one_of("0123456789");
-    let integer = digit.discard().repeat(1..);
-    let parser = space()
-        * one_of("23456789TJQKA")
-            .convert(solution_b::Card::try_from)
-            .repeat(5)
-        + space() * integer.collect().convert(|x| x.parse::<u64>());
-    let mut hands = input
+fn solution_b(bids: &[Bid]) -> u64 {
+    let mut hands = bids
         .iter()
-        .map(|s| parser.parse(s.as_bytes()).unwrap())
-        .map(|(cards, bet)| (solution_b::Hand::from_cards(cards), bet))
+        .map(|bid| {
+            let cards = bid
+                .cards
+                .iter()
+                .map(|c| solution_b::Card::try_from(*c).unwrap())
+                .collect();
+            (solution_b::Hand::from_cards(cards), bid.bet)
+        })
         .collect::<Vec<_>>();
     hands.sort_by(|(hand1, _), (hand2, _)| hand1.cmp(hand2));
     hands
@@ -254,20 +270,33 @@ fn solution_b(input: &[String]) -> u64 {
 
 #[test]
 fn test_solutions() {
-    let input = util::raw_to_strings(
+    let input = parse(
         "32T3K 765
         T55J5 684
         KK677 28
         KTJJT 220
         QQQJA 483",
-    );
+    )
+    .unwrap();
     assert_eq!(solution_a(&input), 6440);
     assert_eq!(solution_b(&input), 5905);
 }
 
-fn main() {
-    println!("input:");
-    let input = util::get_input_rows();
-    println!("Answer puzzle A: {}", solution_a(&input));
-    println!("Answer puzzle B: {}", solution_b(&input));
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Vec<Bid>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
 }
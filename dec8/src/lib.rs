@@ -0,0 +1,245 @@
+use std::{collections::HashMap, fmt::Display, iter::successors};
+
+use pom::utf8::*;
+use util::{
+    cycle::{self, Cycle},
+    math::crt,
+    parser::{parse_str, ParseError},
+    Direction, Solution,
+};
+
+type Tag = String;
+
+fn space<'a>() -> Parser<'a, ()> {
+    one_of("\n \t\r").repeat(0..).discard()
+}
+
+fn directions<'a>() -> Parser<'a, Vec<Direction>> {
+    one_of("LR").convert(Direction::try_from).repeat(1..)
+}
+
+fn tag<'a>() -> Parser<'a, Tag> {
+    one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890")
+        .repeat(3)
+        .map(|s| s.into_iter().collect())
+}
+
+fn pair_of_tags<'a>() -> Parser<'a, (Tag, Tag)> {
+    sym('(') * space() * tag() - space() - sym(',') - space() + tag() - space() - sym(')')
+}
+
+#[derive(Debug)]
+pub struct WastelandMap {
+    directions: Vec<Direction>,
+    map: HashMap<Tag, (Tag, Tag)>,
+}
+
+impl WastelandMap {
+    fn new_from_str(s: &str) -> Result<WastelandMap, ParseError> {
+        let parser = directions()
+            + space()
+                * ((tag() + space() * sym('=') * space() * pair_of_tags() - space()).repeat(1..));
+        let (directions, entries) = parse_str(&parser, s)?;
+
+        Ok(WastelandMap {
+            directions,
+            map: entries.into_iter().collect(),
+        })
+    }
+
+    fn walk_until(&self, start_node: Tag, end_condition: impl Fn(&String) -> bool) -> u64 {
+        let (walk, _tag) = self
+            .directions
+            .iter()
+            .cycle()
+            .scan(start_node, |tag, direction| {
+                if end_condition(tag) {
+                    None
+                } else {
+                    let pair = self.map.get(tag).unwrap();
+                    let newtag = match direction {
+                        Direction::W => pair.0.clone(),
+                        _ => pair.1.clone(),
+                    };
+                    *tag = newtag;
+                    Some(tag.clone())
+                }
+            })
+            .enumerate()
+            .last()
+            .unwrap();
+        walk as u64 + 1
+    }
+
+    fn walk(&self, start_node: Tag) -> u64 {
+        self.walk_until(start_node, |s| s == "ZZZ")
+    }
+
+    fn step(&self, (tag, position): &(Tag, usize)) -> (Tag, usize) {
+        let pair = self.map.get(tag).unwrap();
+        let tag = match self.directions[*position] {
+            Direction::W => pair.0.clone(),
+            _ => pair.1.clone(),
+        };
+        (tag, (position + 1) % self.directions.len())
+    }
+
+    fn trajectory(&self, start_node: Tag) -> Trajectory {
+        let cycle = cycle::find((start_node.clone(), 0), |state| self.step(state));
+        let hits = successors(Some((start_node, 0)), |state| Some(self.step(state)))
+            .take(cycle.prefix + cycle.period)
+            .enumerate()
+            .filter(|(_, (tag, _))| tag.ends_with('Z'))
+            .map(|(step, (_, position))| (position, step as u64))
+            .collect();
+        Trajectory { cycle, hits }
+    }
+}
+
+#[derive(Debug)]
+struct Trajectory {
+    cycle: Cycle,
+    hits: Vec<(usize, u64)>,
+}
+
+impl Trajectory {
+    fn is_hit(&self, step: u64) -> bool {
+        let index = self.cycle.index(step as usize) as u64;
+        self.hits.iter().any(|(_, hit)| *hit == index)
+    }
+
+    fn cycle_hits(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hits
+            .iter()
+            .map(|(_, hit)| *hit)
+            .filter(|hit| *hit >= self.cycle.prefix as u64)
+    }
+}
+
+fn solution_a(map: &WastelandMap) -> u64 {
+    map.walk("AAA".to_string())
+}
+
+fn solution_b(map: &WastelandMap) -> u64 {
+    let trajectories = map
+        .map
+        .keys()
+        .filter(|s| s.ends_with('A'))
+        .map(|tag| map.trajectory(tag.clone()))
+        .collect::<Vec<_>>();
+    util::debug!("trajectories: {:?}", trajectories);
+
+    let prefix = trajectories
+        .iter()
+        .map(|t| t.cycle.prefix as u64)
+        .max()
+        .unwrap();
+    if let Some(step) = (0..prefix).find(|step| trajectories.iter().all(|t| t.is_hit(*step))) {
+        return step;
+    }
+
+    trajectories
+        .iter()
+        .fold(vec![(0, 1)], |congruences, trajectory| {
+            congruences
+                .iter()
+                .flat_map(|congruence| {
+                    trajectory.cycle_hits().filter_map(move |hit| {
+                        crt([*congruence, (hit as i64, trajectory.cycle.period as i64)])
+                    })
+                })
+                .collect()
+        })
+        .into_iter()
+        .map(|(remainder, modulus)| {
+            let (remainder, modulus) = (remainder as u64, modulus as u64);
+            if remainder >= prefix {
+                remainder
+            } else {
+                remainder + (prefix - remainder).div_ceil(modulus) * modulus
+            }
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn test_solutions() {
+    use util::math::lcm_of;
+    let input1 = "RL
+
+        AAA = (BBB, CCC)
+        BBB = (DDD, EEE)
+        CCC = (ZZZ, GGG)
+        DDD = (DDD, DDD)
+        EEE = (EEE, EEE)
+        GGG = (GGG, GGG)
+        ZZZ = (ZZZ, ZZZ)";
+
+    assert_eq!(solution_a(&WastelandMap::new_from_str(input1).unwrap()), 2);
+    let input2 = "LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)";
+    assert_eq!(solution_a(&WastelandMap::new_from_str(input2).unwrap()), 6);
+
+    let input3 = "LR
+
+    11A = (11B, XXX)
+    11B = (XXX, 11Z)
+    11Z = (11B, XXX)
+    22A = (22B, XXX)
+    22B = (22C, 22C)
+    22C = (22Z, 22Z)
+    22Z = (22B, 22B)
+    XXX = (XXX, XXX)";
+    assert_eq!(solution_b(&WastelandMap::new_from_str(input3).unwrap()), 6);
+
+    let input4 = "L
+
+    11A = (11B, 11B)
+    11B = (11Z, 11Z)
+    11Z = (11C, 11C)
+    11C = (11Z, 11Z)
+    22A = (22Z, 22Z)
+    22Z = (22B, 22B)
+    22B = (22C, 22C)
+    22C = (22Z, 22Z)";
+    let map = WastelandMap::new_from_str(input4).unwrap();
+    let first_arrivals =
+        ["11A", "22A"].map(|tag| map.walk_until(tag.to_string(), |s| s.ends_with('Z')));
+    assert_eq!(lcm_of(first_arrivals), Some(2));
+    assert_eq!(solution_b(&map), 4);
+
+    let trajectory = map.trajectory("22A".to_string());
+    assert_eq!(
+        trajectory.cycle,
+        Cycle {
+            prefix: 1,
+            period: 3
+        }
+    );
+    assert_eq!(trajectory.hits, vec![(0, 1)]);
+    assert!(trajectory.is_hit(7));
+    assert!(!trajectory.is_hit(8));
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = WastelandMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        WastelandMap::new_from_str(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
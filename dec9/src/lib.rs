@@ -0,0 +1,73 @@
+use pom::parser::*;
+use std::fmt::Display;
+use util::{
+    parser::{int, parse_lines, space, ParseError},
+    Solution,
+};
+
+fn sequence_extrapolation(sequence: &[i64]) -> (i64, i64) {
+    let first = *sequence.first().unwrap();
+    let last = *sequence.last().unwrap();
+    let iter1 = sequence.iter();
+    let differences = iter1
+        .clone()
+        .zip(iter1.clone().skip(1))
+        .map(|(x, y)| y - x)
+        .collect::<Vec<_>>();
+    if differences.iter().any(|x| *x != 0) {
+        let (f, l) = sequence_extrapolation(&differences);
+        (first - f, last + l)
+    } else {
+        (first, last)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let parser = space() * (int().name("int") - space()).repeat(1..) - end();
+    parse_lines(&parser, input)
+}
+
+fn solution_a(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|sequence| sequence_extrapolation(sequence).1)
+        .sum()
+}
+
+fn solution_b(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|sequence| sequence_extrapolation(sequence).0)
+        .sum()
+}
+
+#[test]
+fn test_solutions() {
+    let input = parse(
+        "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45",
+    )
+    .unwrap();
+    assert_eq!(solution_a(&input), 114);
+    assert_eq!(solution_b(&input), 2);
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_a(input: &Self::Input) -> impl Display {
+        solution_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> impl Display {
+        solution_b(input)
+    }
+}
@@ -0,0 +1,147 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    pub fn state_after<S>(&self, start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.index(n)).fold(start, |state, _| step(&state))
+    }
+}
+
+pub fn find<S: Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut current = start;
+    loop {
+        if let Some(&prefix) = seen.get(&current) {
+            return Cycle {
+                prefix,
+                period: seen.len() - prefix,
+            };
+        }
+        let next = step(&current);
+        let i = seen.len();
+        seen.insert(current, i);
+        current = next;
+    }
+}
+
+pub fn state_after<S: Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut current = start;
+    for i in 0..n {
+        if let Some(&prefix) = seen.get(&current) {
+            let index = Cycle {
+                prefix,
+                period: i - prefix,
+            }
+            .index(n);
+            return seen.into_iter().find(|(_, j)| *j == index).unwrap().0;
+        }
+        let next = step(&current);
+        seen.insert(current, i);
+        current = next;
+    }
+    current
+}
+
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start, |state, _| step(&state));
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+pub fn floyd<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut tortoise = start;
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle { prefix, period }
+}
+
+#[test]
+fn test_cycle() {
+    let step = |x: &usize| if *x < 7 { x + 1 } else { 5 };
+    let cycle = Cycle {
+        prefix: 5,
+        period: 3,
+    };
+    assert_eq!(find(0, step), cycle);
+    assert_eq!(brent(0, step), cycle);
+    assert_eq!(floyd(0, step), cycle);
+    assert_eq!(
+        find(5, step),
+        Cycle {
+            prefix: 0,
+            period: 3
+        }
+    );
+    assert_eq!(
+        brent(5, step),
+        Cycle {
+            prefix: 0,
+            period: 3
+        }
+    );
+    assert_eq!(
+        floyd(5, step),
+        Cycle {
+            prefix: 0,
+            period: 3
+        }
+    );
+
+    assert_eq!(state_after(0, step, 3), 3);
+    assert_eq!(state_after(0, step, 9), 6);
+    assert_eq!(state_after(0, step, 1000000000), 7);
+    assert_eq!(cycle.state_after(0, step, 1000000000), 7);
+    assert_eq!(state_after(0, |x| x + 1, 1000), 1000);
+}
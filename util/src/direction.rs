@@ -0,0 +1,180 @@
+use crate::Point;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+pub enum Direction {
+    E,
+    N,
+    W,
+    S,
+}
+
+impl Direction {
+    pub fn all() -> [Direction; 4] {
+        [Direction::N, Direction::E, Direction::S, Direction::W]
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::E => Direction::N,
+            Direction::N => Direction::W,
+            Direction::W => Direction::S,
+            Direction::S => Direction::E,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::E => Direction::S,
+            Direction::N => Direction::E,
+            Direction::W => Direction::N,
+            Direction::S => Direction::W,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::E => Direction::W,
+            Direction::N => Direction::S,
+            Direction::W => Direction::E,
+            Direction::S => Direction::N,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::E | Direction::W)
+    }
+
+    pub fn offset(&self) -> Point {
+        match self {
+            Direction::E => Point::new(1, 0),
+            Direction::N => Point::new(0, -1),
+            Direction::W => Point::new(-1, 0),
+            Direction::S => Point::new(0, 1),
+        }
+    }
+
+    pub fn move_in(
+        &self,
+        coordinate: (usize, usize),
+        size: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        self.move_steps(coordinate, 1, size)
+    }
+
+    pub fn move_steps(
+        &self,
+        (col, row): (usize, usize),
+        steps: usize,
+        (width, height): (usize, usize),
+    ) -> Option<(usize, usize)> {
+        match self {
+            Direction::E => {
+                Some((col + steps, row)).filter(|(col, row)| *col < width && *row < height)
+            }
+            Direction::N => row.checked_sub(steps).map(|row| (col, row)),
+            Direction::W => col.checked_sub(steps).map(|col| (col, row)),
+            Direction::S => {
+                Some((col, row + steps)).filter(|(col, row)| *col < width && *row < height)
+            }
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'E' | 'R' | '>' => Ok(Direction::E),
+            'N' | 'U' | '^' => Ok(Direction::N),
+            'W' | 'L' | '<' => Ok(Direction::W),
+            'S' | 'D' | 'v' => Ok(Direction::S),
+            c => Err(format!("invalid direction '{c}'")),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    pub fn all() -> [Direction8; 8] {
+        use Direction8::*;
+        [N, NE, E, SE, S, SW, W, NW]
+    }
+
+    pub fn offset(&self) -> Point {
+        use Direction8::*;
+        match self {
+            N => Point::new(0, -1),
+            NE => Point::new(1, -1),
+            E => Point::new(1, 0),
+            SE => Point::new(1, 1),
+            S => Point::new(0, 1),
+            SW => Point::new(-1, 1),
+            W => Point::new(-1, 0),
+            NW => Point::new(-1, -1),
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction8 {
+        Direction8::all()[(*self as usize + 7) % 8]
+    }
+
+    pub fn turn_right(&self) -> Direction8 {
+        Direction8::all()[(*self as usize + 1) % 8]
+    }
+
+    pub fn opposite(&self) -> Direction8 {
+        Direction8::all()[(*self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::E => Direction8::E,
+            Direction::N => Direction8::N,
+            Direction::W => Direction8::W,
+            Direction::S => Direction8::S,
+        }
+    }
+}
+
+#[test]
+fn test_direction() {
+    for d in Direction::all() {
+        assert_eq!(d.turn_left().turn_right(), d);
+        assert_eq!(d.turn_left().turn_left(), d.opposite());
+        assert_eq!(d.offset() + d.opposite().offset(), Point::default());
+        assert_eq!(Direction8::from(d).offset(), d.offset());
+    }
+    assert_eq!(Direction::N.turn_right(), Direction::E);
+    assert_eq!(Direction::try_from('U'), Ok(Direction::N));
+    assert_eq!(Direction::try_from('>'), Ok(Direction::E));
+    assert_eq!(Direction::try_from('L'), Ok(Direction::W));
+    assert_eq!(Direction::try_from('v'), Ok(Direction::S));
+    assert!(Direction::try_from('x').is_err());
+    assert_eq!(Direction::S.move_steps((2, 2), 1, (3, 3)), None);
+    assert_eq!(Direction::W.move_steps((2, 2), 2, (3, 3)), Some((0, 2)));
+
+    assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+    assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+    assert_eq!(Direction8::SW.opposite(), Direction8::NE);
+    assert_eq!(
+        Direction8::all()
+            .iter()
+            .map(|d| d.offset())
+            .fold(Point::default(), |a, b| a + b),
+        Point::default()
+    );
+}
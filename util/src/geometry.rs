@@ -0,0 +1,107 @@
+use crate::{math::gcd, Direction, Point};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Polygon { vertices }
+    }
+
+    pub fn from_commands(commands: impl IntoIterator<Item = (Direction, i64)>) -> Self {
+        let vertices = commands
+            .into_iter()
+            .scan(Point::default(), |corner, (direction, length)| {
+                *corner += direction.offset() * length;
+                Some(*corner)
+            })
+            .collect();
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| (*a, *b))
+    }
+
+    pub fn twice_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i64>()
+            .abs()
+    }
+
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)) as i64)
+            .sum()
+    }
+
+    pub fn interior_points(&self) -> i64 {
+        (self.twice_area() - self.boundary_points() + 2) / 2
+    }
+
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn on_boundary(&self, p: Point) -> bool {
+        self.edges().any(|(a, b)| {
+            (b.x - a.x) * (p.y - a.y) == (p.x - a.x) * (b.y - a.y)
+                && a.min(b).x <= p.x
+                && p.x <= a.max(b).x
+                && a.min(b).y <= p.y
+                && p.y <= a.max(b).y
+        })
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.on_boundary(p)
+            || self
+                .edges()
+                .filter(|(a, b)| (a.y > p.y) != (b.y > p.y))
+                .filter(|(a, b)| {
+                    let cross = (b.x - a.x) * (p.y - a.y) - (p.x - a.x) * (b.y - a.y);
+                    (cross > 0) == (b.y > a.y)
+                })
+                .count()
+                % 2
+                == 1
+    }
+}
+
+#[test]
+fn test_polygon() {
+    let square = Polygon::from_commands([
+        (Direction::E, 3),
+        (Direction::S, 3),
+        (Direction::W, 3),
+        (Direction::N, 3),
+    ]);
+    assert_eq!(square.vertices()[0], Point::new(3, 0));
+    assert_eq!(square.twice_area(), 18);
+    assert_eq!(square.boundary_points(), 12);
+    assert_eq!(square.interior_points(), 4);
+    assert_eq!(square.lattice_points(), 16);
+    assert!(square.contains(Point::new(1, 2)));
+    assert!(square.contains(Point::new(3, 2)));
+    assert!(square.on_boundary(Point::new(0, 0)));
+    assert!(!square.contains(Point::new(4, 1)));
+    assert!(!square.contains(Point::new(-1, 1)));
+
+    let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(0, 4)]);
+    assert_eq!(triangle.twice_area(), 16);
+    assert_eq!(triangle.boundary_points(), 12);
+    assert_eq!(triangle.interior_points(), 3);
+    assert!(triangle.contains(Point::new(1, 2)));
+    assert!(triangle.on_boundary(Point::new(2, 2)));
+    assert!(!triangle.contains(Point::new(3, 2)));
+}
@@ -0,0 +1,92 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "inputs";
+
+pub struct Input {
+    raw: String,
+}
+
+impl Input {
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    pub fn rows(&self) -> Vec<String> {
+        crate::raw_to_strings(&self.raw)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    pub fn read(&self) -> std::io::Result<Input> {
+        let raw = match self {
+            Source::File(path) => std::fs::read_to_string(path)?,
+            Source::Stdin => {
+                let mut raw = String::new();
+                std::io::stdin().read_to_string(&mut raw)?;
+                raw
+            }
+        };
+        Ok(Input { raw })
+    }
+}
+
+pub struct InputLoader {
+    root: PathBuf,
+}
+
+impl InputLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputLoader { root: root.into() }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(std::env::var_os(INPUT_DIR_VAR).unwrap_or(DEFAULT_INPUT_DIR.into()))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.root.join(format!("day{day:02}.txt"))
+    }
+
+    pub fn source(&self, day: u32) -> Source {
+        let path = self.path(day);
+        if path.is_file() {
+            Source::File(path)
+        } else {
+            Source::Stdin
+        }
+    }
+
+    pub fn load(&self, day: u32) -> std::io::Result<Input> {
+        self.source(day).read()
+    }
+}
+
+#[test]
+fn test_input_loader() {
+    let root = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("day07.txt"), "32T3K 765\nT55J5 684\n").unwrap();
+
+    let loader = InputLoader::new(&root);
+    assert_eq!(loader.path(7), root.join("day07.txt"));
+    assert_eq!(loader.source(7), Source::File(root.join("day07.txt")));
+    assert_eq!(loader.source(8), Source::Stdin);
+
+    let input = loader.load(7).unwrap();
+    assert_eq!(input.raw(), "32T3K 765\nT55J5 684\n");
+    assert_eq!(input.rows(), vec!["32T3K 765", "T55J5 684"]);
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,234 @@
+use std::ops::{Range, Sub};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        [
+            Interval::new(self.start, self.end.min(other.start)),
+            Interval::new(self.start.max(other.end), self.end),
+        ]
+        .into_iter()
+        .filter(|interval| !interval.is_empty())
+        .collect()
+    }
+
+    pub fn split_at(&self, value: T) -> (Interval<T>, Interval<T>) {
+        let value = value.clamp(self.start, self.end);
+        (
+            Interval::new(self.start, value),
+            Interval::new(value, self.end),
+        )
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Interval<T> {
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { intervals: vec![] }
+    }
+
+    fn normalized(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort();
+        let mut result: Vec<Interval<T>> = vec![];
+        for interval in intervals {
+            match result.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => result.push(interval),
+            }
+        }
+        RangeSet { intervals: result }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        self.intervals.push(interval);
+        *self = Self::normalized(std::mem::take(&mut self.intervals));
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        Self::normalized(self.iter().chain(other.iter()).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        Self::normalized(
+            self.iter()
+                .flat_map(|a| other.iter().map(|b| a.intersection(b)))
+                .collect(),
+        )
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        Self::normalized(
+            self.iter()
+                .flat_map(|a| {
+                    other.iter().fold(vec![*a], |remaining, b| {
+                        remaining
+                            .iter()
+                            .flat_map(|interval| interval.difference(b))
+                            .collect()
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    pub fn split_at(&self, value: T) -> (RangeSet<T>, RangeSet<T>) {
+        let (below, above) = self.iter().map(|interval| interval.split_at(value)).unzip();
+        (Self::normalized(below), Self::normalized(above))
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + std::iter::Sum> RangeSet<T> {
+    pub fn len(&self) -> T {
+        self.iter().map(Interval::len).sum()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().collect())
+    }
+}
+
+impl<T: Copy + Ord> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalized(vec![interval])
+    }
+}
+
+#[test]
+fn test_interval() {
+    let a = Interval::new(5, 10);
+    assert_eq!(a.len(), 5);
+    assert!(a.contains(5));
+    assert!(a.contains(9));
+    assert!(!a.contains(10));
+    assert!(Interval::new(3, 1).is_empty());
+    assert_eq!(Interval::new(3, 1).len(), 0);
+    assert_eq!(Interval::from(1..4), Interval::new(1, 4));
+
+    assert_eq!(a.intersection(&(1..6).into()), Interval::new(5, 6));
+    assert_eq!(a.intersection(&(6..106).into()), Interval::new(6, 10));
+    assert_eq!(a.intersection(&(0..100).into()), a);
+    assert_eq!(a.intersection(&(5..6).into()), Interval::new(5, 6));
+    assert!(a.intersection(&(10..110).into()).is_empty());
+    assert!(!a.overlaps(&(1..5).into()));
+    assert!(!a.overlaps(&(11..111).into()));
+    assert!(a.overlaps(&(9..11).into()));
+
+    assert_eq!(
+        Interval::new(10, 13).difference(&(11..12).into()),
+        vec![Interval::new(10, 11), Interval::new(12, 13)]
+    );
+    assert_eq!(
+        Interval::new(1, 101).difference(&(101..201).into()),
+        vec![Interval::new(1, 101)]
+    );
+    assert_eq!(Interval::new(1, 5).difference(&(0..10).into()), vec![]);
+
+    assert_eq!(a.split_at(7), (Interval::new(5, 7), Interval::new(7, 10)));
+    assert_eq!(a.split_at(0), (Interval::new(5, 5), a));
+}
+
+#[test]
+fn test_range_set() {
+    let set = [1..3, 8..10, 2..5, 5..6, 12..12]
+        .into_iter()
+        .map(Interval::from)
+        .collect::<RangeSet<i64>>();
+    assert_eq!(
+        set.iter().copied().collect::<Vec<_>>(),
+        vec![Interval::new(1, 6), Interval::new(8, 10)]
+    );
+    assert_eq!(set.len(), 7);
+    assert_eq!(set.min(), Some(1));
+    assert!(set.contains(9));
+    assert!(!set.contains(6));
+
+    let other = RangeSet::from(Interval::new(4, 9));
+    assert_eq!(set.union(&other), RangeSet::from(Interval::new(1, 10)));
+    assert_eq!(
+        set.intersection(&other).iter().copied().collect::<Vec<_>>(),
+        vec![Interval::new(4, 6), Interval::new(8, 9)]
+    );
+    assert_eq!(
+        set.difference(&other).iter().copied().collect::<Vec<_>>(),
+        vec![Interval::new(1, 4), Interval::new(9, 10)]
+    );
+    assert!(other.difference(&set.union(&other)).is_empty());
+
+    let (below, above) = set.split_at(5);
+    assert_eq!(below, RangeSet::from(Interval::new(1, 5)));
+    assert_eq!(
+        above.iter().copied().collect::<Vec<_>>(),
+        vec![Interval::new(5, 6), Interval::new(8, 10)]
+    );
+
+    let mut set = RangeSet::new();
+    assert!(set.is_empty());
+    set.insert(Interval::new(3, 4));
+    set.insert(Interval::new(1, 3));
+    assert_eq!(set, RangeSet::from(Interval::new(1, 4)));
+}
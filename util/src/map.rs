@@ -0,0 +1,467 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut, Range},
+};
+
+use crate::{
+    parser::{parse_grid, ParseError},
+    Direction, Direction8, Point,
+};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Map<T> {
+    fields: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T> Map<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Map {
+            fields: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn new_from_strings(strings: &[String]) -> Self
+    where
+        T: From<char>,
+    {
+        let width = strings[0].len();
+        let height = strings.len();
+        let fields = strings
+            .iter()
+            .flat_map(|s| s.chars().map(|c| c.into()))
+            .collect();
+        Map {
+            fields,
+            width,
+            height,
+        }
+    }
+
+    pub fn try_from_str(input: &str) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        let (fields, width) = parse_grid(input)?;
+        Ok(Map {
+            height: fields.len() / width,
+            fields,
+            width,
+        })
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn contains(&self, (col, row): (usize, usize)) -> bool {
+        col < self.width && row < self.height
+    }
+
+    fn index_of(&self, (col, row): (usize, usize)) -> Option<usize> {
+        self.contains((col, row)).then_some(col + self.width * row)
+    }
+
+    fn coordinate_of(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn at(&self, coordinate: (usize, usize)) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.get(coordinate).cloned()
+    }
+
+    pub fn get(&self, coordinate: (usize, usize)) -> Option<&T> {
+        self.index_of(coordinate).map(|i| &self.fields[i])
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_usize()?)
+    }
+
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        let col = point.x.rem_euclid(self.width as i64) as usize;
+        let row = point.y.rem_euclid(self.height as i64) as usize;
+        &self[(col, row)]
+    }
+
+    pub fn get_mut(&mut self, coordinate: (usize, usize)) -> Option<&mut T> {
+        self.index_of(coordinate).map(|i| &mut self.fields[i])
+    }
+
+    pub fn set(&mut self, coordinate: (usize, usize), value: T) -> Option<T> {
+        self.get_mut(coordinate)
+            .map(|field| std::mem::replace(field, value))
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.fields[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width);
+        self.fields.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|col| self.col(col))
+    }
+
+    pub fn coordinates(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.fields.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.coordinates().zip(self.fields.iter())
+    }
+
+    pub fn enumerate_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.fields
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, field)| ((i % width, i / width), field))
+    }
+
+    pub fn neighbors_with<'a>(
+        &'a self,
+        coordinate: (usize, usize),
+        offsets: impl IntoIterator<Item = Point> + 'a,
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        Point::from(coordinate)
+            .neighbors(offsets)
+            .filter_map(|p| p.to_usize())
+            .filter_map(|c| self.get(c).map(|v| (c, v)))
+    }
+
+    pub fn neighbors4(
+        &self,
+        coordinate: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbors_with(coordinate, Direction::all().map(|d| d.offset()))
+    }
+
+    pub fn neighbors8(
+        &self,
+        coordinate: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbors_with(coordinate, Direction8::all().map(|d| d.offset()))
+    }
+
+    pub fn find(&self, tile: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.fields
+            .iter()
+            .position(|field| field == tile)
+            .map(|i| self.coordinate_of(i))
+    }
+
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut((usize, usize)) -> T) -> Self {
+        Map {
+            fields: (0..width * height)
+                .map(|i| (i % width, i / width))
+                .map(f)
+                .collect(),
+            width,
+            height,
+        }
+    }
+
+    pub fn transpose(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map::from_fn(self.height, self.width, |(col, row)| {
+            self[(row, col)].clone()
+        })
+    }
+
+    pub fn rotate_cw(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map::from_fn(self.height, self.width, |(col, row)| {
+            self[(row, self.height - 1 - col)].clone()
+        })
+    }
+
+    pub fn rotate_ccw(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map::from_fn(self.height, self.width, |(col, row)| {
+            self[(self.width - 1 - row, col)].clone()
+        })
+    }
+
+    pub fn flip_horizontal(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map::from_fn(self.width, self.height, |(col, row)| {
+            self[(self.width - 1 - col, row)].clone()
+        })
+    }
+
+    pub fn flip_vertical(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map::from_fn(self.width, self.height, |(col, row)| {
+            self[(col, self.height - 1 - row)].clone()
+        })
+    }
+
+    pub fn view(&self, cols: Range<usize>, rows: Range<usize>) -> View<'_, T> {
+        assert!(cols.start <= cols.end && cols.end <= self.width);
+        assert!(rows.start <= rows.end && rows.end <= self.height);
+        View {
+            map: self,
+            origin: (cols.start, rows.start),
+            width: cols.len(),
+            height: rows.len(),
+        }
+    }
+
+    pub fn row_view(&self, row: usize) -> View<'_, T> {
+        self.view(0..self.width, row..row + 1)
+    }
+
+    pub fn col_view(&self, col: usize) -> View<'_, T> {
+        self.view(col..col + 1, 0..self.height)
+    }
+
+    pub fn move_direction(
+        &self,
+        coordinate: (usize, usize),
+        d: Direction,
+    ) -> Option<((usize, usize), T)>
+    where
+        T: Clone,
+    {
+        d.move_in(coordinate, self.size())
+            .and_then(|c| self.at(c).map(|v| (c, v)))
+    }
+}
+
+pub struct View<'a, T> {
+    map: &'a Map<T>,
+    origin: (usize, usize),
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn get(&self, (col, row): (usize, usize)) -> Option<&'a T> {
+        if col < self.width && row < self.height {
+            self.map.get((self.origin.0 + col, self.origin.1 + row))
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.height);
+        let start = self.origin.0 + self.map.width * (self.origin.1 + row);
+        &self.map.fields[start..start + self.width]
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &'a T> {
+        let view = *self;
+        (0..self.height).map(move |row| &view.row(row)[col])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let view = *self;
+        (0..self.height).map(move |row| view.row(row))
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        self.rows().enumerate().flat_map(|(row, fields)| {
+            fields
+                .iter()
+                .enumerate()
+                .map(move |(col, field)| ((col, row), field))
+        })
+    }
+
+    pub fn to_map(&self) -> Map<T>
+    where
+        T: Clone,
+    {
+        Map::from_fn(self.width, self.height, |c| self.get(c).unwrap().clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Map<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: (usize, usize)) -> &Self::Output {
+        self.get(coordinate)
+            .unwrap_or_else(|| panic!("{coordinate:?} is outside the map"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Map<T> {
+    fn index_mut(&mut self, coordinate: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(coordinate)
+            .unwrap_or_else(|| panic!("{coordinate:?} is outside the map"))
+    }
+}
+
+impl<T> Display for Map<T>
+where
+    T: Clone + Into<char>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.cloned().map(Into::into).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_map() {
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Tile(char);
+    impl From<char> for Tile {
+        fn from(value: char) -> Self {
+            Tile(value)
+        }
+    }
+    impl From<Tile> for char {
+        fn from(value: Tile) -> Self {
+            value.0
+        }
+    }
+
+    let mut map = Map::<Tile>::new_from_strings(&crate::raw_to_strings("ab.\n.#c"));
+    assert_eq!(map.size(), (3, 2));
+    assert_eq!(map.get((2, 1)), Some(&Tile('c')));
+    assert_eq!(map.get((3, 0)), None);
+    assert_eq!(map.get((0, 2)), None);
+    assert_eq!(map.set((0, 1), Tile('d')), Some(Tile('.')));
+    assert_eq!(map.set((0, 2), Tile('x')), None);
+    *map.get_mut((1, 0)).unwrap() = Tile('B');
+    map[(2, 0)] = Tile('e');
+    assert_eq!(map[(0, 1)], Tile('d'));
+
+    let chars = |tiles: &mut dyn Iterator<Item = &Tile>| tiles.map(|t| t.0).collect::<String>();
+    assert_eq!(chars(&mut map.row(1)), "d#c");
+    assert_eq!(chars(&mut map.col(1)), "B#");
+    assert_eq!(chars(&mut map.col(2).rev()), "ce");
+    assert_eq!(
+        map.rows().map(|mut r| chars(&mut r)).collect::<Vec<_>>(),
+        vec!["aBe", "d#c"]
+    );
+    assert_eq!(
+        map.cols().map(|mut c| chars(&mut c)).collect::<Vec<_>>(),
+        vec!["ad", "B#", "ec"]
+    );
+    assert_eq!(map.enumerate().nth(4), Some(((1, 1), &Tile('#'))));
+    map.enumerate_mut()
+        .filter(|(c, _)| c.1 == 1)
+        .for_each(|(_, t)| *t = Tile('.'));
+    assert_eq!(map.find(&Tile('e')), Some((2, 0)));
+    assert_eq!(map.find(&Tile('#')), None);
+    assert_eq!(map.to_string(), "aBe\n...");
+    assert_eq!(Map::new(2, 2, Tile('.')).to_string(), "..\n..");
+}
+
+#[test]
+fn test_try_from_str() {
+    let map = Map::<char>::try_from_str("ab\r\ncd\n").unwrap();
+    assert_eq!(map.size(), (2, 2));
+    assert_eq!(map.at((0, 1)), Some('c'));
+    let error = Map::<char>::try_from_str("ab\nc").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+#[test]
+fn test_signed_access() {
+    let map = Map::<char>::new_from_strings(&crate::raw_to_strings("ab\ncd"));
+    assert_eq!(map.get_point(Point::new(1, 0)), Some(&'b'));
+    assert_eq!(map.get_point(Point::new(-1, 0)), None);
+    assert_eq!(map.get_point(Point::new(0, 2)), None);
+    assert_eq!(map.get_wrapping(Point::new(-1, 0)), &'b');
+    assert_eq!(map.get_wrapping(Point::new(4, -3)), &'c');
+    assert_eq!(map.get_wrapping(Point::new(-5, 7)), &'d');
+}
+
+#[test]
+fn test_neighbors() {
+    let map = Map::<char>::new_from_strings(&crate::raw_to_strings("abc\ndef\nghi"));
+    let chars = |neighbors: &mut dyn Iterator<Item = ((usize, usize), &char)>| {
+        neighbors.map(|(_, c)| *c).collect::<String>()
+    };
+    assert_eq!(chars(&mut map.neighbors4((1, 1))), "bfhd");
+    assert_eq!(chars(&mut map.neighbors4((0, 0))), "bd");
+    assert_eq!(chars(&mut map.neighbors8((1, 1))), "bcfihgda");
+    assert_eq!(chars(&mut map.neighbors8((2, 2))), "fhe");
+    assert_eq!(
+        map.neighbors_with(
+            (0, 0),
+            [Point::new(2, 1), Point::new(1, 2), Point::new(-1, 2)]
+        )
+        .collect::<Vec<_>>(),
+        vec![((2, 1), &'f'), ((1, 2), &'h')]
+    );
+}
+
+#[test]
+fn test_transforms() {
+    let map = Map::<char>::new_from_strings(&crate::raw_to_strings("abc\ndef"));
+    assert_eq!(map.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(map.rotate_cw().to_string(), "da\neb\nfc");
+    assert_eq!(map.rotate_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(map.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(map.flip_vertical().to_string(), "def\nabc");
+    assert_eq!(map.rotate_cw().rotate_ccw(), map);
+    assert_eq!(
+        map.rotate_cw().rotate_cw(),
+        map.flip_horizontal().flip_vertical()
+    );
+
+    let view = map.view(1..3, 0..2);
+    assert_eq!(view.size(), (2, 2));
+    assert_eq!(view.get((0, 1)), Some(&'e'));
+    assert_eq!(view.get((2, 0)), None);
+    assert_eq!(view.row(1), &['e', 'f']);
+    assert_eq!(view.col(1).collect::<String>(), "cf");
+    assert_eq!(view.to_map().to_string(), "bc\nef");
+    assert_eq!(view.enumerate().last(), Some(((1, 1), &'f')));
+    assert_eq!(map.row_view(1).row(0), &['d', 'e', 'f']);
+    assert_eq!(map.col_view(2).to_map().to_string(), "c\nf");
+}
@@ -0,0 +1,148 @@
+use std::ops::{Div, Mul, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($abs:expr; $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                $abs(self)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        })*
+    };
+}
+
+integer!(|n| n; u8, u16, u32, u64, u128, usize);
+integer!(|n: Self| n.abs(); i8, i16, i32, i64, i128, isize);
+
+pub fn gcd<T: Integer>(mut n: T, mut m: T) -> T {
+    while m != T::ZERO {
+        (n, m) = (m, n % m);
+    }
+    n.abs()
+}
+
+pub fn checked_lcm<T: Integer>(n: T, m: T) -> Option<T> {
+    if n == T::ZERO || m == T::ZERO {
+        Some(T::ZERO)
+    } else {
+        (n / gcd(n, m)).checked_mul(m).map(T::abs)
+    }
+}
+
+pub fn lcm<T: Integer>(n: T, m: T) -> T {
+    checked_lcm(n, m).expect("lcm overflowed")
+}
+
+pub fn lcm_of<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| checked_lcm(acc, value))
+}
+
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            let (g, p, _) = extended_gcd(m1, m2);
+            if (r2 - r1) % g != 0 {
+                return None;
+            }
+            let step = (m2 / g) as i128;
+            let k = ((r2 - r1) / g) as i128 * p as i128 % step;
+            let modulus = i64::try_from(m1 as i128 * step).ok()?;
+            let remainder = (r1 as i128 + m1 as i128 * k).rem_euclid(modulus as i128);
+            Some((remainder as i64, modulus))
+        })
+}
+
+#[test]
+fn test_math() {
+    assert_eq!(gcd(12_u64, 18), 6);
+    assert_eq!(gcd(0_u64, 7), 7);
+    assert_eq!(gcd(0_u64, 0), 0);
+    assert_eq!(gcd(-12_i32, 18), 6);
+    assert_eq!(gcd(12_i64, -18), 6);
+    assert_eq!(lcm(4_u8, 6), 12);
+    assert_eq!(lcm(-4_i64, 6), 12);
+    assert_eq!(lcm(0_u32, 6), 0);
+    assert_eq!(checked_lcm(200_u8, 3), None);
+    assert_eq!(lcm_of([2_u64, 3, 4, 5]), Some(60));
+    assert_eq!(lcm_of(Vec::<u64>::new()), Some(1));
+    assert_eq!(lcm_of([u64::MAX, u64::MAX - 1]), None);
+
+    let (g, x, y) = extended_gcd(240, 46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+    assert_eq!(extended_gcd(-4, 6).0, 2);
+
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(4, 8), None);
+    assert_eq!(mod_pow(4, 13, 497), 445);
+    assert_eq!(mod_pow(2, 10, 1), 0);
+    assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+    assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+    assert_eq!(crt([]), Some((0, 1)));
+}
@@ -1,6 +1,248 @@
 use pom::parser::*;
+use std::fmt::Display;
 use std::str::{self};
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub error: pom::Error,
+}
+
+fn innermost(error: &pom::Error) -> &pom::Error {
+    match error {
+        pom::Error::Expect { inner, .. } => innermost(inner),
+        pom::Error::Custom {
+            inner: Some(inner), ..
+        } => innermost(inner),
+        e => e,
+    }
+}
+
+fn describe(error: &pom::Error) -> String {
+    match error {
+        pom::Error::Incomplete => "unexpected end of input".to_string(),
+        pom::Error::Mismatch { message, .. }
+        | pom::Error::Conversion { message, .. }
+        | pom::Error::Custom {
+            message,
+            inner: None,
+            ..
+        } => message.clone(),
+        pom::Error::Expect { message, inner, .. }
+        | pom::Error::Custom {
+            message,
+            inner: Some(inner),
+            ..
+        } => format!("{message}: {}", describe(inner)),
+    }
+}
+
+impl ParseError {
+    pub fn new(input: &str, error: pom::Error) -> Self {
+        let bytes = input.as_bytes();
+        let position = match innermost(&error) {
+            pom::Error::Incomplete => bytes.len(),
+            pom::Error::Mismatch { position, .. }
+            | pom::Error::Conversion { position, .. }
+            | pom::Error::Expect { position, .. }
+            | pom::Error::Custom { position, .. } => *position,
+        }
+        .min(bytes.len());
+        let line_start = bytes[..position]
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |i| i + 1);
+        let line_end = bytes[position..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map_or(bytes.len(), |i| position + i);
+        ParseError {
+            line: bytes[..line_start].iter().filter(|b| **b == b'\n').count() + 1,
+            column: String::from_utf8_lossy(&bytes[line_start..position])
+                .chars()
+                .count()
+                + 1,
+            source_line: String::from_utf8_lossy(&bytes[line_start..line_end]).into_owned(),
+            error,
+        }
+    }
+
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.line.to_string().len();
+        writeln!(
+            f,
+            "{} at line {}, column {}",
+            describe(&self.error),
+            self.line,
+            self.column
+        )?;
+        writeln!(f, "{:width$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{:width$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+pub trait Parse<'a, O> {
+    fn parse_at(&self, input: &'a [u8], start: usize) -> pom::Result<(O, usize)>;
+}
+
+impl<'a, O> Parse<'a, O> for Parser<'a, u8, O> {
+    fn parse_at(&self, input: &'a [u8], start: usize) -> pom::Result<(O, usize)> {
+        self.parse_at(input, start)
+    }
+}
+
+impl<'a, O> Parse<'a, O> for pom::utf8::Parser<'a, O> {
+    fn parse_at(&self, input: &'a [u8], start: usize) -> pom::Result<(O, usize)> {
+        self.parse_at(input, start)
+    }
+}
+
+pub fn parse_str<'a, O>(parser: &impl Parse<'a, O>, input: &'a str) -> Result<O, ParseError> {
+    let (output, position) = parser
+        .parse_at(input.as_bytes(), 0)
+        .map_err(|e| ParseError::new(input, e))?;
+    if input.as_bytes()[position..]
+        .iter()
+        .all(|b| b.is_ascii_whitespace())
+    {
+        Ok(output)
+    } else {
+        let error = pom::Error::Mismatch {
+            message: "expected end of input".to_string(),
+            position,
+        };
+        Err(ParseError::new(input, error))
+    }
+}
+
+pub fn parse_lines<'a, O>(
+    parser: &impl Parse<'a, O>,
+    input: &'a str,
+) -> Result<Vec<O>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(n, line)| parse_str(parser, line).map_err(|e| e.offset_lines(n)))
+        .collect()
+}
+
+pub fn parse_grid<T>(input: &str) -> Result<(Vec<T>, usize), ParseError>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    let error =
+        |position, message| ParseError::new(input, pom::Error::Mismatch { message, position });
+    let body = input.trim_end_matches(['\n', '\r']);
+    if body.is_empty() {
+        return Err(ParseError::new(input, pom::Error::Incomplete));
+    }
+
+    let mut tiles = vec![];
+    let mut width = None;
+    let mut offset = 0;
+    for line in body.split('\n') {
+        let row = line.strip_suffix('\r').unwrap_or(line);
+        let mut count = 0;
+        for (i, c) in row.char_indices() {
+            if width == Some(count) {
+                return Err(error(offset + i, format!("row longer than {count} tiles")));
+            }
+            let tile = T::try_from(c).map_err(|e| {
+                let message = e.to_string();
+                let position = offset + i;
+                ParseError::new(input, pom::Error::Conversion { message, position })
+            })?;
+            tiles.push(tile);
+            count += 1;
+        }
+        match width {
+            Some(width) if count < width => {
+                let message = format!("row shorter than {width} tiles");
+                return Err(error(offset + row.len(), message));
+            }
+            None if count == 0 => return Err(error(offset, "empty row".to_string())),
+            _ => width = Some(count),
+        }
+        offset += line.len() + 1;
+    }
+    Ok((tiles, width.unwrap()))
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "1 2 3\n4 x 6\n";
+    let parser = list(posint(), sym(b' ')) - end();
+    let error = parse_lines(&parser, input).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.source_line, "4 x 6");
+    assert_eq!(
+        error.to_string().lines().skip(1).collect::<Vec<_>>(),
+        vec!["  |", "2 | 4 x 6", "  |  ^"]
+    );
+
+    let parser = (list(posint(), sym(b' ')) - sym(b'\n')).repeat(1..) - end();
+    let error = parse_str(&parser, input).unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    let error = parse_str(&posint(), "1 2").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 2));
+
+    let error = parse_str(&posint(), "99999999999").unwrap_err();
+    assert!(matches!(error.error, pom::Error::Conversion { .. }));
+}
+
+#[test]
+fn test_parse_grid() {
+    #[derive(Debug, PartialEq)]
+    struct Digit(u32);
+    impl TryFrom<char> for Digit {
+        type Error = String;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            value
+                .to_digit(10)
+                .map(Digit)
+                .ok_or(format!("invalid digit '{value}'"))
+        }
+    }
+
+    let (tiles, width) = parse_grid::<Digit>("12\r\n34\n").unwrap();
+    assert_eq!(width, 2);
+    assert_eq!(tiles, vec![Digit(1), Digit(2), Digit(3), Digit(4)]);
+    assert_eq!(parse_grid::<char>("ab\ncd").unwrap().0[2], 'c');
+
+    let error = parse_grid::<Digit>("12\n3x\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    assert_eq!(error.source_line, "3x");
+    assert!(error.to_string().contains("invalid digit 'x'"));
+
+    let error = parse_grid::<Digit>("12\n3\n45").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    let error = parse_grid::<Digit>("12\n345").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 3));
+    let error = parse_grid::<Digit>("12\n\n34").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert!(parse_grid::<Digit>("").is_err());
+    assert!(parse_grid::<Digit>("\n").is_err());
+}
+
 pub fn space<'a>() -> Parser<'a, u8, ()> {
     one_of(b" \t\r\n").repeat(0..).discard()
 }
@@ -10,14 +252,14 @@ pub fn posint<'a>() -> Parser<'a, u8, u32> {
     integer
         .collect()
         .convert(str::from_utf8)
-        .map(|str| str.parse::<u32>().unwrap())
+        .convert(|str| str.parse::<u32>())
 }
 
 pub mod utf8 {
     use pom::utf8::*;
     pub fn posint<'a>() -> Parser<'a, u64> {
         let integer = (one_of("123456789") - one_of("0123456789").repeat(0..)) | sym('0');
-        integer.collect().map(|str| str.parse::<u64>().unwrap())
+        integer.collect().convert(|str| str.parse::<u64>())
     }
 
     pub fn space<'a>() -> Parser<'a, ()> {
@@ -31,5 +273,5 @@ pub fn int<'a>() -> Parser<'a, u8, i64> {
     integer
         .collect()
         .convert(str::from_utf8)
-        .map(|str| str.parse::<i64>().unwrap())
+        .convert(|str| str.parse::<i64>())
 }
@@ -0,0 +1,143 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Direction, Direction8};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn min(self, other: Point) -> Point {
+        Point::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    pub fn max(self, other: Point) -> Point {
+        Point::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    pub fn neighbors(
+        &self,
+        offsets: impl IntoIterator<Item = Point>,
+    ) -> impl Iterator<Item = Point> {
+        let center = *self;
+        offsets.into_iter().map(move |offset| center + offset)
+    }
+
+    pub fn neighbors4(&self) -> impl Iterator<Item = Point> {
+        self.neighbors(Direction::all().map(|d| d.offset()))
+    }
+
+    pub fn neighbors8(&self) -> impl Iterator<Item = Point> {
+        self.neighbors(Direction8::all().map(|d| d.offset()))
+    }
+
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point {
+            x: x as i64,
+            y: y as i64,
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[test]
+fn test_point() {
+    let a = Point::new(1, -2);
+    let b = Point::from((3_usize, 4_usize));
+    assert_eq!(a + b, Point::new(4, 2));
+    assert_eq!(a - b, Point::new(-2, -6));
+    assert_eq!(-a, Point::new(-1, 2));
+    assert_eq!(a * 3, Point::new(3, -6));
+    let mut c = a;
+    c += b;
+    c -= a * 2;
+    assert_eq!(c, Point::new(2, 6));
+    assert_eq!(a.manhattan(b), 8);
+    assert_eq!(a.min(b), Point::new(1, -2));
+    assert_eq!(a.max(b), Point::new(3, 4));
+    assert_eq!(Point::new(1, 5).min(b), Point::new(1, 4));
+    assert_eq!(Point::new(1, 5).max(b), Point::new(3, 5));
+    assert_eq!(a.to_usize(), None);
+    assert_eq!(
+        a.neighbors4().collect::<Vec<_>>(),
+        vec![
+            Point::new(1, -3),
+            Point::new(2, -2),
+            Point::new(1, -1),
+            Point::new(0, -2)
+        ]
+    );
+    assert_eq!(a.neighbors8().count(), 8);
+    assert!(a.neighbors8().all(|p| p != a && a.manhattan(p) <= 2));
+    assert_eq!(
+        a.neighbors([Point::new(2, 1), Point::new(-2, 1)])
+            .collect::<Vec<_>>(),
+        vec![Point::new(3, -1), Point::new(-1, -1)]
+    );
+    assert_eq!(b.to_usize(), Some((3, 4)));
+}
@@ -0,0 +1,136 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{Map, Point};
+
+pub fn flood_fill<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for neighbor in neighbors(&node) {
+            if visited.insert(neighbor.clone()) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    visited
+}
+
+pub fn components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Vec<HashSet<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+    for node in nodes {
+        if !seen.contains(&node) {
+            let component = flood_fill(node, &mut neighbors);
+            seen.extend(component.iter().cloned());
+            components.push(component);
+        }
+    }
+    components
+}
+
+pub fn label<T: PartialEq>(map: &Map<T>) -> (Map<usize>, usize) {
+    let regions = components(map.coordinates(), |c| {
+        map.neighbors4(*c)
+            .filter(|(_, v)| *v == &map[*c])
+            .map(|(n, _)| n)
+            .collect::<Vec<_>>()
+    });
+    let mut labels = Map::new(map.width, map.height, 0);
+    for (label, region) in regions.iter().enumerate() {
+        for c in region {
+            labels[*c] = label;
+        }
+    }
+    (labels, regions.len())
+}
+
+pub fn area(region: &HashSet<Point>) -> usize {
+    region.len()
+}
+
+pub fn perimeter(region: &HashSet<Point>) -> usize {
+    region
+        .iter()
+        .flat_map(|p| p.neighbors4())
+        .filter(|p| !region.contains(p))
+        .count()
+}
+
+pub fn enclosed(min: Point, max: Point, is_boundary: impl Fn(Point) -> bool) -> HashSet<Point> {
+    let (outer_min, outer_max) = (min - Point::new(1, 1), max + Point::new(1, 1));
+    let outside = flood_fill(outer_min, |p| {
+        p.neighbors4()
+            .filter(|n| {
+                outer_min.x <= n.x && n.x <= outer_max.x && outer_min.y <= n.y && n.y <= outer_max.y
+            })
+            .filter(|n| !is_boundary(*n))
+            .collect::<Vec<_>>()
+    });
+    (min.y..=max.y)
+        .flat_map(|y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+        .filter(|p| !is_boundary(*p) && !outside.contains(p))
+        .collect()
+}
+
+#[test]
+fn test_regions() {
+    let map = Map::<char>::new_from_strings(&crate::raw_to_strings(
+        "#####
+#...#
+#.#.#
+#...#
+####.",
+    ));
+    let inside = flood_fill((1, 1), |c| {
+        map.neighbors4(*c)
+            .filter(|(_, v)| **v == '.')
+            .map(|(n, _)| n)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(inside.len(), 8);
+
+    let (labels, count) = label(&map);
+    assert_eq!(count, 4);
+    assert_eq!(labels[(1, 1)], labels[(3, 3)]);
+    assert_ne!(labels[(0, 0)], labels[(2, 2)]);
+    assert_ne!(labels[(0, 0)], labels[(4, 4)]);
+
+    let ring = map
+        .enumerate()
+        .filter(|(_, v)| **v == '.')
+        .map(|(c, _)| Point::from(c))
+        .filter(|p| *p != Point::new(4, 4))
+        .collect::<HashSet<_>>();
+    assert_eq!(area(&ring), 8);
+    assert_eq!(perimeter(&ring), 16);
+
+    let walls = |p: Point| map.get_point(p) == Some(&'#');
+    assert_eq!(
+        enclosed(Point::new(0, 0), Point::new(4, 4), walls),
+        ring.clone()
+    );
+    let open = |p: Point| walls(p) && p != Point::new(4, 3);
+    assert!(enclosed(Point::new(0, 0), Point::new(4, 4), open).is_empty());
+    assert_eq!(
+        components(ring.iter().copied(), |p| p
+            .neighbors4()
+            .filter(|n| ring.contains(n))
+            .collect::<Vec<_>>())
+        .len(),
+        1
+    );
+}
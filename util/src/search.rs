@@ -0,0 +1,395 @@
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    hash::Hash,
+};
+
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashMap<N, u64>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, distance)) = queue.pop_front() {
+        for neighbor in neighbors(&node) {
+            if !distances.contains_key(&neighbor) {
+                distances.insert(neighbor.clone(), distance + 1);
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+pub trait Queue<T> {
+    fn push(&mut self, priority: u64, item: T);
+    fn pop(&mut self) -> Option<(u64, T)>;
+}
+
+struct Entry<T>(u64, T);
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+pub struct HeapQueue<T> {
+    heap: BinaryHeap<Entry<T>>,
+}
+
+impl<T> HeapQueue<T> {
+    pub fn new() -> Self {
+        HeapQueue {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<T> Default for HeapQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Queue<T> for HeapQueue<T> {
+    fn push(&mut self, priority: u64, item: T) {
+        self.heap.push(Entry(priority, item));
+    }
+
+    fn pop(&mut self) -> Option<(u64, T)> {
+        self.heap
+            .pop()
+            .map(|Entry(priority, item)| (priority, item))
+    }
+}
+
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    current: usize,
+}
+
+impl<T> BucketQueue<T> {
+    pub fn new() -> Self {
+        BucketQueue {
+            buckets: vec![],
+            current: 0,
+        }
+    }
+}
+
+impl<T> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Queue<T> for BucketQueue<T> {
+    fn push(&mut self, priority: u64, item: T) {
+        let priority = priority as usize;
+        assert!(
+            priority >= self.current,
+            "bucket queue priorities must not decrease"
+        );
+        if priority >= self.buckets.len() {
+            self.buckets.resize_with(priority + 1, Vec::new);
+        }
+        self.buckets[priority].push(item);
+    }
+
+    fn pop(&mut self) -> Option<(u64, T)> {
+        while self.current < self.buckets.len() {
+            if let Some(item) = self.buckets[self.current].pop() {
+                return Some((self.current as u64, item));
+            }
+            self.current += 1;
+        }
+        None
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+    pub edges: Vec<u64>,
+}
+
+type Costs<S> = HashMap<S, (Vec<(S, u64)>, u64)>;
+
+fn search<D: Dijkstra + ?Sized>(
+    graph: &D,
+    starts: impl IntoIterator<Item = D::State>,
+    is_end: impl Fn(&D::State) -> bool,
+    heuristic: impl Fn(&D::State) -> u64,
+    mut queue: impl Queue<(u64, D::State)>,
+    all: bool,
+) -> (Costs<D::State>, Vec<D::State>) {
+    let mut costs: Costs<D::State> = HashMap::new();
+    for start in starts {
+        queue.push(heuristic(&start), (0, start.clone()));
+        costs.insert(start, (vec![], 0));
+    }
+
+    let mut ends: Vec<D::State> = vec![];
+    let mut settled = HashSet::new();
+    while let Some((_, (current_cost, current_state))) = queue.pop() {
+        if costs[&current_state].1 < current_cost {
+            continue;
+        }
+        if ends.first().is_some_and(|end| costs[end].1 < current_cost) {
+            break;
+        }
+        if is_end(&current_state) {
+            ends.push(current_state);
+            if all {
+                continue;
+            }
+            break;
+        }
+        settled.insert(current_state.clone());
+        for (neighbor, cost) in graph.neighbors(&current_state) {
+            let path_cost = current_cost + cost;
+            match costs.get_mut(&neighbor) {
+                Some((predecessors, known)) if path_cost == *known => {
+                    if !predecessors.is_empty() && !settled.contains(&neighbor) {
+                        predecessors.push((current_state.clone(), cost));
+                    }
+                }
+                Some((_, known)) if *known < path_cost => {}
+                _ => {
+                    costs.insert(
+                        neighbor.clone(),
+                        (vec![(current_state.clone(), cost)], path_cost),
+                    );
+                    queue.push(path_cost + heuristic(&neighbor), (path_cost, neighbor));
+                }
+            }
+        }
+    }
+    (costs, ends)
+}
+
+fn path<S: Clone + Eq + Hash>(costs: &Costs<S>, end: S) -> Path<S> {
+    let cost = costs[&end].1;
+    let mut states = vec![end];
+    let mut edges = vec![];
+    while let Some((previous, edge)) = costs[states.last().unwrap()].0.first() {
+        states.push(previous.clone());
+        edges.push(*edge);
+    }
+    states.reverse();
+    edges.reverse();
+    Path {
+        cost,
+        states,
+        edges,
+    }
+}
+
+fn all_paths<S: Clone + Eq + Hash>(costs: &Costs<S>, end: &S) -> Vec<Path<S>> {
+    let predecessors = &costs[end].0;
+    if predecessors.is_empty() {
+        return vec![Path {
+            cost: 0,
+            states: vec![end.clone()],
+            edges: vec![],
+        }];
+    }
+    predecessors
+        .iter()
+        .flat_map(|(previous, edge)| {
+            all_paths(costs, previous).into_iter().map(move |mut path| {
+                path.cost += edge;
+                path.states.push(end.clone());
+                path.edges.push(*edge);
+                path
+            })
+        })
+        .collect()
+}
+
+pub trait Dijkstra {
+    type State: Clone + Eq + Hash + Debug;
+
+    fn neighbors(&self, state: &Self::State) -> Vec<(Self::State, u64)>;
+
+    fn solve(
+        &self,
+        start: Self::State,
+        is_end: impl Fn(&Self::State) -> bool,
+    ) -> Option<Path<Self::State>> {
+        self.solve_from([start], is_end)
+    }
+
+    fn solve_from(
+        &self,
+        starts: impl IntoIterator<Item = Self::State>,
+        is_end: impl Fn(&Self::State) -> bool,
+    ) -> Option<Path<Self::State>> {
+        let (costs, ends) = search(self, starts, is_end, |_| 0, HeapQueue::new(), false);
+        ends.into_iter().next().map(|end| path(&costs, end))
+    }
+
+    fn solve_with(
+        &self,
+        queue: impl Queue<(u64, Self::State)>,
+        start: Self::State,
+        is_end: impl Fn(&Self::State) -> bool,
+    ) -> Option<Path<Self::State>> {
+        let (costs, ends) = search(self, [start], is_end, |_| 0, queue, false);
+        ends.into_iter().next().map(|end| path(&costs, end))
+    }
+
+    fn solve_all(
+        &self,
+        start: Self::State,
+        is_end: impl Fn(&Self::State) -> bool,
+    ) -> Vec<Path<Self::State>> {
+        let (costs, ends) = search(self, [start], is_end, |_| 0, HeapQueue::new(), true);
+        ends.iter().flat_map(|end| all_paths(&costs, end)).collect()
+    }
+
+    fn solve_astar(
+        &self,
+        start: Self::State,
+        is_end: impl Fn(&Self::State) -> bool,
+        heuristic: impl Fn(&Self::State) -> u64,
+    ) -> Option<Path<Self::State>> {
+        let (costs, ends) = search(self, [start], is_end, heuristic, HeapQueue::new(), false);
+        ends.into_iter().next().map(|end| path(&costs, end))
+    }
+
+    fn distances(
+        &self,
+        starts: impl IntoIterator<Item = Self::State>,
+    ) -> HashMap<Self::State, u64> {
+        let (costs, _) = search(self, starts, |_| false, |_| 0, HeapQueue::new(), false);
+        costs
+            .into_iter()
+            .map(|(state, (_, cost))| (state, cost))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+struct Graph(Vec<Vec<(usize, u64)>>);
+
+#[cfg(test)]
+impl Dijkstra for Graph {
+    type State = usize;
+
+    fn neighbors(&self, state: &usize) -> Vec<(usize, u64)> {
+        self.0[*state].clone()
+    }
+}
+
+#[test]
+fn test_search() {
+    let graph = Graph(vec![
+        vec![(1, 7), (2, 2)],
+        vec![(3, 1)],
+        vec![(1, 3), (3, 8)],
+        vec![],
+        vec![(3, 1)],
+        vec![(0, 1)],
+    ]);
+    assert_eq!(
+        graph.solve(0, |s| *s == 3),
+        Some(Path {
+            cost: 6,
+            states: vec![0, 2, 1, 3],
+            edges: vec![2, 3, 1]
+        })
+    );
+    assert_eq!(
+        graph.solve_with(BucketQueue::new(), 0, |s| *s == 3),
+        graph.solve(0, |s| *s == 3)
+    );
+    assert_eq!(graph.solve(3, |s| *s == 0), None);
+    assert_eq!(graph.solve(3, |s| *s == 3).unwrap().states, vec![3]);
+    assert_eq!(
+        graph.solve_from([0, 4], |s| *s == 3).unwrap().states,
+        vec![4, 3]
+    );
+    assert_eq!(
+        graph.solve_astar(5, |s| *s == 3, |s| [3, 1, 4, 0, 1, 4][*s]),
+        Some(Path {
+            cost: 7,
+            states: vec![5, 0, 2, 1, 3],
+            edges: vec![1, 2, 3, 1]
+        })
+    );
+    assert_eq!(
+        graph.distances([0]),
+        HashMap::from([(0, 0), (1, 5), (2, 2), (3, 6)])
+    );
+
+    let diamond = Graph(vec![
+        vec![(1, 1), (2, 1), (3, 3)],
+        vec![(3, 2)],
+        vec![(3, 2)],
+        vec![],
+    ]);
+    let mut paths = diamond.solve_all(0, |s| *s == 3);
+    paths.sort_by(|a, b| a.states.cmp(&b.states));
+    assert_eq!(
+        paths.iter().map(|p| p.states.clone()).collect::<Vec<_>>(),
+        vec![vec![0, 1, 3], vec![0, 2, 3], vec![0, 3]]
+    );
+    assert!(paths.iter().all(|p| p.cost == 3));
+    assert_eq!(paths[2].edges, vec![3]);
+
+    let cycle = Graph(vec![vec![(1, 0)], vec![(0, 0), (2, 1)], vec![]]);
+    let path = cycle.solve(0, |s| *s == 2).unwrap();
+    assert_eq!((path.states, path.cost), (vec![0, 1, 2], 1));
+    assert_eq!(cycle.solve_all(0, |s| *s == 2).len(), 1);
+    assert_eq!(cycle.solve_all(1, |s| *s == 2).len(), 1);
+
+    let mut heap = HeapQueue::new();
+    let mut buckets = BucketQueue::new();
+    for (priority, item) in [(3, 0.5), (1, 1.5), (2, 2.5), (1, 3.5)] {
+        heap.push(priority, item);
+        buckets.push(priority, item);
+    }
+    for queue in [&mut heap as &mut dyn Queue<f64>, &mut buckets] {
+        assert_eq!(
+            std::iter::from_fn(|| queue.pop())
+                .map(|(priority, _)| priority)
+                .collect::<Vec<_>>(),
+            vec![1, 1, 2, 3]
+        );
+    }
+
+    let distances = bfs([0_i64], |n| {
+        [n - 1, n + 2].into_iter().filter(|n| n.abs() <= 3)
+    });
+    assert_eq!(distances[&3], 3);
+    assert_eq!(distances[&-3], 3);
+    assert_eq!(distances[&1], 2);
+    assert_eq!(distances.len(), 7);
+}
@@ -0,0 +1,144 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::parser::ParseError;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::A, Part::B]
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            s => Err(format!("invalid part '{s}'")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => write!(f, "A"),
+            Part::B => write!(f, "B"),
+        }
+    }
+}
+
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_a(input: &Self::Input) -> impl Display;
+    fn part_b(input: &Self::Input) -> impl Display;
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub answer: String,
+    pub time: Duration,
+}
+
+pub struct Output {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+pub type Measure<'a> = dyn FnMut(Stage, &mut dyn FnMut()) + 'a;
+
+pub struct Day {
+    pub day: u32,
+    run: fn(&str, &[Part]) -> Result<Output, ParseError>,
+    bench: fn(&str, &mut Measure) -> Result<(), ParseError>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            run: run::<S>,
+            bench: bench::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Output, ParseError> {
+        (self.run)(input, parts)
+    }
+
+    pub fn bench(&self, input: &str, measure: &mut Measure) -> Result<(), ParseError> {
+        (self.bench)(input, measure)
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Output, ParseError> {
+    let (input, parse_time) = timed(|| S::parse(input));
+    let input = input?;
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let (answer, time) = timed(|| match part {
+                Part::A => S::part_a(&input).to_string(),
+                Part::B => S::part_b(&input).to_string(),
+            });
+            Answer {
+                part: *part,
+                answer,
+                time,
+            }
+        })
+        .collect();
+    Ok(Output {
+        parse_time,
+        answers,
+    })
+}
+
+fn bench<S: Solution>(input: &str, measure: &mut Measure) -> Result<(), ParseError> {
+    let parsed = S::parse(input)?;
+    measure(Stage::Parse, &mut || {
+        let _ = black_box(S::parse(black_box(input)));
+    });
+    measure(Stage::Part(Part::A), &mut || {
+        black_box(S::part_a(black_box(&parsed)).to_string());
+    });
+    measure(Stage::Part(Part::B), &mut || {
+        black_box(S::part_b(black_box(&parsed)).to_string());
+    });
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use std::{
+    collections::{hash_map, HashMap},
+    fmt::Display,
+};
+
+use crate::Point;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SparseMap<T> {
+    fields: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseMap<T> {
+    fn default() -> Self {
+        SparseMap {
+            fields: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseMap<T> {
+    pub fn new() -> Self {
+        SparseMap::default()
+    }
+
+    pub fn new_from_strings(strings: &[String], skip: char) -> Self
+    where
+        T: From<char>,
+    {
+        strings
+            .iter()
+            .enumerate()
+            .flat_map(|(y, s)| {
+                s.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c != skip)
+                    .map(move |(x, c)| (Point::from((x, y)), c.into()))
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.fields.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.fields.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.fields.get_mut(&point)
+    }
+
+    pub fn set(&mut self, point: Point, value: T) -> Option<T> {
+        self.fields.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.fields.remove(&point)
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, Point, T> {
+        self.fields.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.fields.keys().copied()
+    }
+
+    pub fn find(&self, tile: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.fields
+            .iter()
+            .find(|(_, field)| *field == tile)
+            .map(|(point, _)| *point)
+    }
+
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.points();
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), p| (min.min(p), max.max(p))))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseMap {
+            fields: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Display for SparseMap<T>
+where
+    T: Clone + Into<char>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            let row = (min.x..=max.x)
+                .map(|x| self.get(Point::new(x, y)).map_or('.', |t| t.clone().into()))
+                .collect::<String>();
+            write!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_sparse_map() {
+    let mut map = SparseMap::<char>::new_from_strings(&crate::raw_to_strings("#..\n.#."), '.');
+    assert_eq!(map.len(), 2);
+    assert!(map.contains(Point::new(1, 1)));
+    assert_eq!(map.set(Point::new(-2, -1), '@'), None);
+    assert_eq!(map.get(Point::new(-2, -1)), Some(&'@'));
+    assert_eq!(map.find(&'@'), Some(Point::new(-2, -1)));
+    assert_eq!(map.bounds(), Some((Point::new(-2, -1), Point::new(1, 1))));
+    assert_eq!(map.to_string(), "@...\n..#.\n...#");
+    assert_eq!(map.remove(Point::new(-2, -1)), Some('@'));
+    assert_eq!(map.to_string(), "#.\n.#");
+    assert_eq!(SparseMap::<char>::new().bounds(), None);
+}
@@ -0,0 +1,83 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+pub const VERBOSITY_VAR: &str = "AOC_VERBOSE";
+
+const UNSET: u8 = u8::MAX;
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(UNSET);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl Level {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity.min(Level::Trace as u8), Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        UNSET => {
+            let verbosity = std::env::var(VERBOSITY_VAR)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0);
+            set_verbosity(verbosity);
+            verbosity.min(Level::Trace as u8)
+        }
+        verbosity => verbosity,
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= verbosity()
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::trace::enabled($level) {
+            eprintln!("[{}] {}", $level.name(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::log!($crate::trace::Level::Info, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log!($crate::trace::Level::Debug, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::log!($crate::trace::Level::Trace, $($arg)*) };
+}
+
+#[test]
+fn test_verbosity() {
+    set_verbosity(2);
+    assert!(enabled(Level::Info));
+    assert!(enabled(Level::Debug));
+    assert!(!enabled(Level::Trace));
+    set_verbosity(7);
+    assert_eq!(verbosity(), 3);
+    assert!(enabled(Level::Trace));
+    set_verbosity(0);
+    assert!(!enabled(Level::Info));
+}